@@ -0,0 +1,41 @@
+//! Prints the JSON Schema of every response type in the official API module,
+//! as a single JSON object keyed by the name of each type.
+//!
+//! ```shell
+//! $ cargo run --example schema --features schemars > schema.json
+//! ```
+
+use std::collections::BTreeMap;
+
+use curseforge::official::prelude::*;
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+
+fn main() {
+    let schemas: [(&str, RootSchema); 9] = [
+        ("Game", schema_for!(DataResponse<Game>)),
+        ("Games", schema_for!(PaginatedDataResponse<Game>)),
+        ("GameVersions", schema_for!(DataResponse<Vec<GameVersions>>)),
+        (
+            "GameVersionTypes",
+            schema_for!(DataResponse<Vec<GameVersionType>>),
+        ),
+        ("Categories", schema_for!(DataResponse<Vec<Category>>)),
+        ("Project", schema_for!(DataResponse<Project>)),
+        (
+            "SearchProjects",
+            schema_for!(PaginatedDataResponse<Project>),
+        ),
+        (
+            "FeaturedProjects",
+            schema_for!(DataResponse<FeaturedProjects>),
+        ),
+        (
+            "ProjectFiles",
+            schema_for!(PaginatedDataResponse<ProjectFile>),
+        ),
+    ];
+    let schemas = schemas.into_iter().collect::<BTreeMap<_, _>>();
+
+    println!("{}", serde_json::to_string_pretty(&schemas).unwrap());
+}
@@ -0,0 +1,65 @@
+//! Contains [`Client`], which makes requests to the CFWidget API.
+
+use crate::cfwidget::endpoints as e;
+use crate::cfwidget::types::Project;
+use crate::Error;
+
+/// Makes requests to the CFWidget API, see [`e::DEFAULT_API_BASE`]. This wraps
+/// the functions in [`e`], which can also be used directly with any
+/// [`isahc::HttpClient`].
+///
+/// CFWidget does not require a token.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: isahc::HttpClient,
+    base: url::Url,
+}
+
+impl Client {
+    /// Constructs a client for the CFWidget API at `base`, such as
+    /// [`e::DEFAULT_API_BASE`].
+    pub fn new<U>(base: U) -> Result<Self, Error>
+    where
+        U: AsRef<str>,
+    {
+        let base = url::Url::parse(base.as_ref())?;
+
+        if base.cannot_be_a_base() {
+            return Err(Error::BadBaseUrl);
+        }
+
+        let inner = isahc::HttpClient::builder()
+            .default_header("accept", "application/json")
+            .build()?;
+
+        Ok(Self { inner, base })
+    }
+
+    /// Get the API base URL that project paths are appended to.
+    pub fn base(&self) -> &url::Url {
+        &self.base
+    }
+
+    /// Requests the project with the ID `project_id`. See [`e::project`] for
+    /// the errors that are specific to CFWidget.
+    pub async fn project(&self, project_id: u32) -> Result<Project, Error> {
+        e::project(&self.inner, &self.base, &project_id.to_string()).await
+    }
+
+    /// Requests a project by the path of its page on the CurseForge website,
+    /// such as `minecraft`, `mc-mods`, and `jei` for
+    /// <https://www.curseforge.com/minecraft/mc-mods/jei>. See [`e::project`]
+    /// for the errors that are specific to CFWidget.
+    pub async fn project_by_path(
+        &self,
+        game: &str,
+        category: &str,
+        slug: &str,
+    ) -> Result<Project, Error> {
+        let path = [game, category, slug]
+            .map(|segment| segment.trim_matches('/'))
+            .join("/");
+
+        e::project(&self.inner, &self.base, &path).await
+    }
+}
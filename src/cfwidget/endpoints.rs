@@ -0,0 +1,105 @@
+//! Functions for building the URLs of the CFWidget API, and for making
+//! requests to it with an [`isahc::HttpClient`].
+
+use std::borrow::Cow;
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode};
+
+use crate::cfwidget::types::Project;
+use crate::{Error, PATH_SEGMENT};
+
+/// This is the CFWidget API base URL.
+pub static DEFAULT_API_BASE: &str = "https://api.cfwidget.com/";
+
+/// Splits a project path into its non-empty segments, with any query or
+/// fragment removed, and with each segment percent-decoded.
+fn path_segments(path: &str) -> impl Iterator<Item = Cow<'_, str>> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| percent_decode_str(segment).decode_utf8_lossy())
+}
+
+/// Normalizes the path of a project, which is either the path of its page on
+/// the CurseForge website, such as `minecraft/mc-mods/jei`, or its numeric ID.
+///
+/// Leading, trailing, and repeated slashes are removed, as is any query or
+/// fragment. Each segment is percent-encoded, after decoding it first so that
+/// a path that is already encoded is not encoded twice.
+///
+/// ```
+/// # use curseforge::cfwidget::endpoints::normalize_path;
+/// assert_eq!(
+///     normalize_path("/minecraft/mc-mods/jei/"),
+///     "minecraft/mc-mods/jei"
+/// );
+/// assert_eq!(normalize_path("238222?version=1.20.1"), "238222");
+/// ```
+pub fn normalize_path(path: &str) -> String {
+    path_segments(path)
+        .map(|segment| utf8_percent_encode(&segment, PATH_SEGMENT).to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Appends the normalized path of a project to `base`, see
+/// [`normalize_path`]. Paths that only differ by the cases that it handles
+/// result in the same URL.
+///
+/// # Panics
+///
+/// If `base` cannot be a base URL, such as a `data:` URL.
+pub fn project_url(base: &url::Url, path: &str) -> url::Url {
+    let mut url = base.clone();
+
+    url.set_query(None);
+    url.set_fragment(None);
+    url.path_segments_mut()
+        .expect("the base URL cannot be a base")
+        .pop_if_empty()
+        .extend(path_segments(path));
+
+    url
+}
+
+/// <https://www.cfwidget.com/>
+///
+/// Requests the project at `path`, which is either its numeric ID or the path
+/// of its page on the CurseForge website, see [`project_url`].
+///
+/// CFWidget responds with `202: Accepted` when it has not fetched the project
+/// before, and has queued it to be fetched. This is returned as
+/// [`Error::InQueue`], and the request should be sent again later. A project
+/// that does not exist is returned as [`Error::NotFound`].
+pub async fn project(
+    client: &isahc::HttpClient,
+    base: &url::Url,
+    path: &str,
+) -> Result<Project, Error> {
+    use futures_lite::io::AsyncReadExt;
+    use isahc::http::StatusCode;
+
+    let uri = project_url(base, path);
+    let response = client.get_async(uri.as_str()).await?;
+    let status = response.status();
+    let mut body = response.into_body();
+    let mut bytes = Vec::new();
+
+    body.read_to_end(&mut bytes).await?;
+
+    match status {
+        StatusCode::ACCEPTED => return Err(Error::InQueue { uri }),
+        StatusCode::NOT_FOUND => return Err(Error::NotFound { uri }),
+        status if !status.is_success() => return Err(Error::from_status(uri, status, bytes)),
+        _ => (),
+    }
+
+    let deser = &mut serde_json::Deserializer::from_slice(&bytes);
+
+    serde_path_to_error::deserialize(deser).map_err(|error| Error::Deserialize {
+        uri,
+        error,
+        bytes: Box::new(bytes),
+    })
+}
@@ -1,7 +1,15 @@
 //! **Do not use this, it is incomplete!**
 
+#[doc(hidden)]
+pub mod client;
+pub mod endpoints;
 pub mod types;
 
+pub use crate::Error;
+pub use client::Client;
+
 pub mod prelude {
+    pub use super::client::Client;
+    pub use super::endpoints as e;
     pub use super::types::*;
 }
@@ -4,7 +4,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "unknown-fields"), serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "allow-unknown-fields"), serde(deny_unknown_fields))]
 pub struct Project {
     pub id: u32,
     pub title: String,
@@ -25,13 +25,13 @@ pub struct Project {
     pub files: Vec<ProjectFile>,
     pub versions: HashMap<String, ProjectFile>,
     pub download: ProjectFile,
-    #[cfg(feature = "unknown-fields")]
+    #[cfg(feature = "allow-unknown-fields")]
     #[serde(flatten)]
     pub other_fields: serde_json::Value,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "unknown-fields"), serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "allow-unknown-fields"), serde(deny_unknown_fields))]
 pub struct ProjectFile {
     pub id: u32,
     pub url: String,
@@ -45,27 +45,27 @@ pub struct ProjectFile {
     pub versions: Vec<String>,
     pub downloads: usize,
     pub uploaded_at: DateTime<Utc>,
-    #[cfg(feature = "unknown-fields")]
+    #[cfg(feature = "allow-unknown-fields")]
     #[serde(flatten)]
     pub other_fields: serde_json::Value,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "unknown-fields"), serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "allow-unknown-fields"), serde(deny_unknown_fields))]
 pub struct ProjectDownloads {
     pub monthly: usize,
     pub total: usize,
-    #[cfg(feature = "unknown-fields")]
+    #[cfg(feature = "allow-unknown-fields")]
     #[serde(flatten)]
     pub other_fields: serde_json::Value,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "unknown-fields"), serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "allow-unknown-fields"), serde(deny_unknown_fields))]
 pub struct ProjectUrls {
     pub curseforge: String,
     pub project: String,
-    #[cfg(feature = "unknown-fields")]
+    #[cfg(feature = "allow-unknown-fields")]
     #[serde(flatten)]
     pub other_fields: serde_json::Value,
 }
@@ -75,18 +75,51 @@ pub enum ReleaseType {
     Release,
     Beta,
     Alpha,
-    #[cfg(feature = "unknown-fields")]
+    #[cfg(feature = "allow-unknown-fields")]
     #[serde(other)]
     Unknown,
 }
 
+/// Converts to the release type of the official API. `Unknown`, which is only
+/// present with the `allow-unknown-fields` feature, becomes
+/// `FileReleaseType::Unknown`.
+#[cfg(feature = "official")]
+impl From<ReleaseType> for crate::official::types::FileReleaseType {
+    fn from(other: ReleaseType) -> Self {
+        match other {
+            ReleaseType::Release => Self::Release,
+            ReleaseType::Beta => Self::Beta,
+            ReleaseType::Alpha => Self::Alpha,
+            #[cfg(feature = "allow-unknown-fields")]
+            ReleaseType::Unknown => Self::Unknown,
+        }
+    }
+}
+
+/// Converts from the release type of the official API. As with the reverse
+/// conversion, `Unknown` becomes `ReleaseType::Unknown`.
+#[cfg(feature = "official")]
+impl From<crate::official::types::FileReleaseType> for ReleaseType {
+    fn from(other: crate::official::types::FileReleaseType) -> Self {
+        use crate::official::types::FileReleaseType;
+
+        match other {
+            FileReleaseType::Release => Self::Release,
+            FileReleaseType::Beta => Self::Beta,
+            FileReleaseType::Alpha => Self::Alpha,
+            #[cfg(feature = "allow-unknown-fields")]
+            FileReleaseType::Unknown => Self::Unknown,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[cfg_attr(not(feature = "unknown-fields"), serde(deny_unknown_fields))]
+#[cfg_attr(not(feature = "allow-unknown-fields"), serde(deny_unknown_fields))]
 pub struct ProjectMember {
     pub title: String,
     pub username: String,
     pub id: u32,
-    #[cfg(feature = "unknown-fields")]
+    #[cfg(feature = "allow-unknown-fields")]
     #[serde(flatten)]
     pub other_fields: serde_json::Value,
 }
@@ -40,6 +40,36 @@
 //! `deny-unknown-fields`, see the documentation on the
 //! [`crate::official::types`] module.
 //!
+//! The optional `schemars` feature derives [`JsonSchema`] for all of the
+//! response types of the `official` module, so that a schema matching the wire
+//! format can be exported for consumers in other languages. See the `schema`
+//! example in the repository.
+//!
+//! The optional `disk-cache` feature adds a `DiskResponseCache`, which keeps
+//! responses on disk between runs of a program, to be revalidated with the API
+//! by their `ETag`. See `ClientOptions::response_cache`.
+//!
+//! The optional `modpack` feature adds methods to `Client` that download a
+//! modpack file and read the projects and files listed in its manifest, such as
+//! `Client::modpack_contents`.
+//!
+//! The optional `testing` feature adds the `official::testing` module, which
+//! builds values of this crate's types from JSON for use in the tests of
+//! dependent crates, without making any requests. It is meant to be enabled
+//! only in `[dev-dependencies]`.
+//!
+//! [`JsonSchema`]: https://docs.rs/schemars/0.8/schemars/trait.JsonSchema.html
+//!
+//! ## Version of isahc
+//!
+//! Retries, throttling, caching, and the other options of `ClientOptions` are
+//! implemented with the interceptors of [isahc], which are an unstable API that
+//! may change in any release. This crate therefore depends on an exact version
+//! of isahc, so a dependent crate that also depends on isahc 1.x must accept
+//! that version.
+//!
+//! [isahc]: https://docs.rs/isahc
+//!
 //! ## Generating Documentation
 //!
 //! The documentation is expected to be built with nightly, and certain features
@@ -47,20 +77,28 @@
 //! the root will change depending on the API modules that you have enabled.
 //!
 //! ```shell
-//! $ cargo +nightly doc --features "cfwidget" --features "allow-unknown-fields"
+//! $ RUSTDOCFLAGS="--cfg docsrs" cargo +nightly doc --features "cfwidget" --features "allow-unknown-fields"
 //! ```
 
 #![deny(rust_2018_idioms)]
 #![warn(missing_docs)]
 // This enables the small box that indicates when a member is only available
 // through a feature gate.
-#![cfg_attr(doc, feature(doc_auto_cfg))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+// The `Deserialize` variant of `Error` is large, but boxing it would be a
+// breaking change for anyone destructuring it.
+#![allow(clippy::result_large_err)]
 
 #[cfg(all(feature = "allow-unknown-fields", feature = "deny-unknown-fields"))]
 compile_error!(
     r#"features "allow-unknown-fields" and "deny-unknown-fields" are mutually exclusive"#
 );
 
+#[cfg(not(any(feature = "official", feature = "cfwidget")))]
+compile_error!(
+    r#"at least one of the features "official" and "cfwidget" must be enabled, re-enable the default features or add `features = ["cfwidget"]` for this crate in `Cargo.toml`"#
+);
+
 #[cfg(feature = "cfwidget")]
 #[cfg_attr(not(feature = "official"), doc(hidden))]
 pub mod cfwidget;
@@ -77,7 +115,24 @@ pub use official::*;
 pub use cfwidget::*;
 
 /// The main error type used throughout the crate.
+///
+/// New variants may be added in minor releases, so a `match` on this type must
+/// always include a catch-all arm.
+///
+/// ```no_run
+/// # async fn example(client: &curseforge::official::Client) {
+/// use curseforge::Error;
+///
+/// match client.project(238222).await {
+///     Ok(project) => println!("found {}", project.name),
+///     Err(Error::Unauthorized { .. }) => eprintln!("check your token"),
+///     Err(Error::StatusNotOk { status, .. }) => eprintln!("got response {}", status),
+///     Err(error) => eprintln!("{}", error),
+/// }
+/// # }
+/// ```
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     /// This is the most useful variant. This will be returned if the API
     /// response failed to parse either as valid JSON, or according to the
@@ -99,9 +154,12 @@ pub enum Error {
     /// have happened.
     #[error("error constructing or receiving a request\n{0}")]
     Request(#[from] isahc::Error),
-    /// A request to a URI that was expected to return successfully with `200:
-    /// OK` has failed to do so. This contains the status code that was recieved
-    /// instead, and the bytes in the body of the response.
+    /// A request to a URI that was expected to return successfully with a
+    /// `2xx` status has failed to do so, such as with `404: Not Found` or `500:
+    /// Internal Server Error`. This contains the status code that was recieved
+    /// instead, and the bytes in the body of the response, which are not
+    /// parsed. The statuses `401` and `403` have their own variants,
+    /// [`Self::Unauthorized`] and [`Self::Forbidden`].
     #[error("got response {status}\nencountered at: {uri}")]
     StatusNotOk {
         /// The URI that the initial request was sent to.
@@ -111,6 +169,68 @@ pub enum Error {
         /// The bytes the body content bytes of the response.
         bytes: Box<Vec<u8>>,
     },
+    /// The API responded successfully, but without the resource that was
+    /// requested, with `"data": null` in the body. This is also returned when
+    /// CFWidget responds with `404: Not Found`.
+    #[error("the resource does not exist\nencountered at: {uri}")]
+    NotFound {
+        /// The URI that the initial request was sent to.
+        uri: url::Url,
+    },
+    /// CFWidget responded with `202: Accepted`, because it has not fetched the
+    /// project before, and has queued it to be fetched. The same request
+    /// should succeed once it has been, usually within a few seconds.
+    #[error(
+        "the project is queued to be fetched by CFWidget, try again later\nencountered at: {uri}"
+    )]
+    InQueue {
+        /// The URI that the initial request was sent to.
+        uri: url::Url,
+    },
+    /// The API responded with `401: Unauthorized`, because the token is
+    /// missing or invalid.
+    #[error("the token was missing or invalid\nencountered at: {uri}")]
+    Unauthorized {
+        /// The URI that the initial request was sent to.
+        uri: url::Url,
+    },
+    /// The API responded with `403: Forbidden`, because the token does not
+    /// grant access to the resource, or its author does not allow it to be
+    /// distributed through the API.
+    #[error("access to the resource is not allowed\nencountered at: {uri}")]
+    Forbidden {
+        /// The URI that the initial request was sent to.
+        uri: url::Url,
+    },
+    /// The body of a response was not valid UTF-8, so it could not be parsed
+    /// as JSON. This may be a description or changelog in another encoding,
+    /// which can be read with [`ClientOptions::lossy_utf8`] enabled.
+    ///
+    /// [`ClientOptions::lossy_utf8`]: crate::official::client::ClientOptions::lossy_utf8
+    #[error("the response is not valid UTF-8\nencountered at: {uri}\n{error}")]
+    InvalidUtf8 {
+        /// The URI that the initial request was sent to.
+        uri: url::Url,
+        /// The source error, with the position of the first invalid byte.
+        #[source]
+        error: std::str::Utf8Error,
+        /// The bytes the body content bytes of the response.
+        bytes: Box<Vec<u8>>,
+    },
+    /// The request was stopped by Cloudflare, which the API and some proxies
+    /// are served behind, before it reached the API. The response was a
+    /// challenge meant for a web browser, which this client cannot solve.
+    /// This is usually caused by sending too many requests, or by the network
+    /// that they were sent from, so waiting, lowering the rate of requests,
+    /// or using another API base may help.
+    #[error(
+        "the request was blocked by Cloudflare before it reached the API, try again later, send \
+         fewer requests, or use another API base\nencountered at: {uri}"
+    )]
+    Blocked {
+        /// The URI that the initial request was sent to.
+        uri: url::Url,
+    },
     /// This variant will wrap an [`isahc::http::Error`] when configuring the
     /// client has failed to produce a stable instance of the backend.
     #[error("error constructing a request\n{0}")]
@@ -118,7 +238,102 @@ pub enum Error {
     /// Variant specifically for when parsing the base URL fails.
     #[error("failed to parse as a URL\n{0}")]
     ParseUrl(#[from] url::ParseError),
-    /// The URL that was provided cannot be used as a base.
+    /// The URL that was provided cannot be used as a base, or has no version
+    /// segment to replace with another version of the API.
     #[error("the URL cannot be an API base")]
     BadBaseUrl,
+    /// The URL of a proxy cannot be used, because it has no scheme, such as
+    /// `http://`, or its credentials could not be removed from it.
+    #[error("the URL cannot be used as a proxy, it may be missing a scheme such as http://")]
+    BadProxyUrl,
+    /// The parameters of a request are known to be invalid, so it was not
+    /// sent. This contains an explanation of the problem.
+    #[error("invalid request parameters\n{0}")]
+    InvalidParams(&'static str),
+    /// A game version type was requested by name, but the game has no type
+    /// whose name or slug matches it.
+    #[error("the game {game_id} has no version type named {name:?}")]
+    UnknownVersionType {
+        /// The ID of the game whose version types were searched.
+        game_id: i32,
+        /// The name that was not found.
+        name: String,
+    },
+    /// The deadline given to a batch method elapsed before the request for
+    /// this item finished, so it was abandoned.
+    #[error("the deadline of the batch elapsed before this request finished")]
+    DeadlineElapsed,
+    /// Two different files would be downloaded to the same path, because
+    /// their names are the same once made safe, ignoring case.
+    #[error("the files {first} and {second} would both be downloaded to {path:?}")]
+    TargetCollision {
+        /// The ID of the file that is listed first.
+        first: i32,
+        /// The ID of the file that is listed second.
+        second: i32,
+        /// The path that both would be downloaded to.
+        path: std::path::PathBuf,
+    },
+    /// Reading the body of a response has failed, such as when the timeout
+    /// elapsed partway through it, or reading or writing a downloaded file has
+    /// failed.
+    #[error("error reading a response or writing a download\n{0}")]
+    Io(#[from] std::io::Error),
+    /// A downloaded modpack file is not a valid zip archive, or does not
+    /// contain a `manifest.json` file.
+    #[cfg(feature = "modpack")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "modpack")))]
+    #[error("error reading a modpack archive\n{0}")]
+    Zip(#[from] zip::result::ZipError),
+}
+
+impl Error {
+    /// Constructs the variant for a response with an unexpected `status`.
+    pub(crate) fn from_status(
+        uri: url::Url,
+        status: isahc::http::StatusCode,
+        bytes: Vec<u8>,
+    ) -> Self {
+        use isahc::http::StatusCode;
+
+        match status {
+            StatusCode::UNAUTHORIZED => Self::Unauthorized { uri },
+            StatusCode::FORBIDDEN => Self::Forbidden { uri },
+            _ => Self::StatusNotOk {
+                uri,
+                status,
+                bytes: Box::new(bytes),
+            },
+        }
+    }
+
+    /// Returns `true` if the request that caused this error may succeed if it
+    /// is sent again. This is the case when the connection failed or timed
+    /// out, when the API responded with `429: Too Many Requests` or any `5xx`
+    /// status, and when CFWidget has queued the project, see
+    /// [`Self::InQueue`].
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::InQueue { .. } => true,
+            Self::Request(error) => error.is_network() || error.is_timeout(),
+            Self::Io(error) => error.kind() == std::io::ErrorKind::TimedOut,
+            Self::StatusNotOk { status, .. } => status.as_u16() == 429 || status.is_server_error(),
+            _ => false,
+        }
+    }
 }
+
+/// The characters that are percent-encoded in a path segment, which are the
+/// same as those that [`url::Url::path_segments_mut`] encodes.
+pub(crate) const PATH_SEGMENT: &percent_encoding::AsciiSet = &percent_encoding::CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'?')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'%');
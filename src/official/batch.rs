@@ -0,0 +1,437 @@
+//! Contains [`Client`] methods that make many requests at once, the
+//! [`Concurrency`] that they are limited by, the [`ChunkedResults`] of those
+//! that split their input, and the internal helpers that they share.
+//!
+//! Each of these methods also has an `_until` variant, which takes an optional
+//! deadline for the whole batch. Once it has elapsed, the requests that are
+//! still outstanding are dropped, and each of the items that they were for is
+//! reported with [`Error::DeadlineElapsed`], alongside the results that did
+//! finish.
+
+use std::future::Future;
+use std::ops::Range;
+use std::pin::pin;
+use std::time::Duration;
+
+use futures_util::future::{self, Either};
+use futures_util::stream::{self, StreamExt};
+
+use crate::official::client::Client;
+use crate::official::request::{CategoriesParams, ProjectChangelogStream, ProjectFilesParams};
+use crate::official::types::{Category, Game, Project, ProjectFile};
+use crate::Error;
+
+/// The maximum number of requests that a batch method will have in flight at
+/// the same time.
+///
+/// Every method that takes a limit accepts either a `usize`, or
+/// [`Concurrency::Client`] to use the limit of the client, see
+/// [`ClientOptions::concurrency`].
+///
+/// [`ClientOptions::concurrency`]: crate::official::client::ClientOptions::concurrency
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Concurrency {
+    /// Use the limit that the client was constructed with.
+    #[default]
+    Client,
+    /// Use this limit for a single call, overriding the client's limit. A
+    /// limit of `0` is treated as `1`.
+    Limit(usize),
+}
+
+impl Concurrency {
+    /// The limit used by a client when neither
+    /// [`ClientOptions::concurrency`] nor
+    /// [`ClientOptions::max_connections`] are set.
+    ///
+    /// [`ClientOptions::concurrency`]: crate::official::client::ClientOptions::concurrency
+    /// [`ClientOptions::max_connections`]: crate::official::client::ClientOptions::max_connections
+    pub const DEFAULT_LIMIT: usize = 8;
+}
+
+impl From<usize> for Concurrency {
+    fn from(other: usize) -> Self {
+        Self::Limit(other)
+    }
+}
+
+/// The results of a batch method that splits its input into chunks, and makes
+/// one request for each chunk, such as [`Client::projects_chunked`].
+///
+/// A chunk that fails does not discard the others. The items from every chunk
+/// that succeeded are kept, and each chunk that failed is reported with the
+/// range of indices into the input that it covered, so that those IDs can be
+/// requested again.
+#[derive(Debug)]
+pub struct ChunkedResults<T> {
+    /// The items returned for every chunk that succeeded, in the order of the
+    /// chunks. The API may leave out IDs that do not exist, so this may have
+    /// fewer items than there were IDs, even when no chunk failed.
+    pub items: Vec<T>,
+    /// The range of input indices and the error of every chunk that failed,
+    /// in the order of the chunks.
+    pub errors: Vec<(Range<usize>, Error)>,
+}
+
+impl<T> ChunkedResults<T> {
+    /// Returns `true` if no chunk failed.
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Converts into the items if no chunk failed, or else the error of the
+    /// first chunk that did, discarding any partial results.
+    pub fn into_result(self) -> Result<Vec<T>, Error> {
+        match self.errors.into_iter().next() {
+            Some((_, error)) => Err(error),
+            None => Ok(self.items),
+        }
+    }
+}
+
+/// Maps every item to a future with `f`, and polls at most `concurrency` of
+/// those futures at the same time. The outputs are collected in the same order
+/// as the items they were produced from.
+pub(crate) async fn bounded_map<I, F, Fut>(items: I, concurrency: usize, f: F) -> Vec<Fut::Output>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future,
+{
+    stream::iter(items)
+        .map(f)
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Like [`bounded_map`], except that once `deadline` has elapsed, the futures
+/// that have not finished are dropped, and `None` is returned in their place.
+/// The futures may finish out of order, so that one slow item does not hold
+/// back the outputs of the others.
+pub(crate) async fn bounded_map_until<I, F, Fut>(
+    items: I,
+    concurrency: usize,
+    deadline: Option<Duration>,
+    mut f: F,
+) -> Vec<Option<Fut::Output>>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future,
+{
+    let items = items.into_iter().collect::<Vec<_>>();
+    let mut outputs = items.iter().map(|_| None).collect::<Vec<_>>();
+    let mut pending = pin!(stream::iter(items.into_iter().enumerate())
+        .map(|(index, item)| {
+            let future = f(item);
+            async move { (index, future.await) }
+        })
+        .buffer_unordered(concurrency.max(1)));
+    let mut timer = deadline.map(futures_timer::Delay::new);
+
+    loop {
+        let next = match &mut timer {
+            Some(timer) => match future::select(pending.next(), timer).await {
+                Either::Left((next, _)) => next,
+                Either::Right(_) => break,
+            },
+            None => pending.next().await,
+        };
+
+        match next {
+            Some((index, output)) => outputs[index] = Some(output),
+            None => break,
+        }
+    }
+
+    outputs
+}
+
+impl Client {
+    /// Resolves a [`Concurrency`] to a limit, which is at least `1`.
+    pub(crate) fn concurrency_limit(&self, concurrency: Concurrency) -> usize {
+        match concurrency {
+            Concurrency::Client => self.concurrency(),
+            Concurrency::Limit(limit) => limit,
+        }
+        .max(1)
+    }
+
+    /// Like [`bounded_map`], with the limit resolved for this client.
+    pub(crate) async fn bounded_map<I, F, Fut>(
+        &self,
+        items: I,
+        concurrency: Concurrency,
+        f: F,
+    ) -> Vec<Fut::Output>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Fut,
+        Fut: Future,
+    {
+        bounded_map(items, self.concurrency_limit(concurrency), f).await
+    }
+
+    /// Makes one request with `f` for each of `ids`, and pairs every ID with
+    /// its result, or with [`Error::DeadlineElapsed`] if it did not finish.
+    async fn by_ids<T, F, Fut>(
+        &self,
+        ids: impl IntoIterator<Item = i32>,
+        concurrency: Concurrency,
+        deadline: Option<Duration>,
+        f: F,
+    ) -> Vec<(i32, Result<T, Error>)>
+    where
+        F: FnMut(i32) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let ids = ids.into_iter().collect::<Vec<_>>();
+        let outputs = bounded_map_until(
+            ids.iter().copied(),
+            self.concurrency_limit(concurrency),
+            deadline,
+            f,
+        )
+        .await;
+
+        ids.into_iter()
+            .zip(outputs)
+            .map(|(id, output)| (id, output.unwrap_or(Err(Error::DeadlineElapsed))))
+            .collect()
+    }
+
+    /// Splits `ids` into chunks of at most `chunk_size`, and requests each
+    /// chunk with `f`. A `chunk_size` of `0` is treated as `1`.
+    async fn chunked<T, F, Fut>(
+        &self,
+        ids: Vec<i32>,
+        chunk_size: usize,
+        concurrency: Concurrency,
+        deadline: Option<Duration>,
+        f: F,
+    ) -> ChunkedResults<T>
+    where
+        F: FnMut(Vec<i32>) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, Error>>,
+    {
+        let chunk_size = chunk_size.max(1);
+        let ranges = ids
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let start = index * chunk_size;
+                start..start + chunk.len()
+            })
+            .collect::<Vec<_>>();
+        let outputs = bounded_map_until(
+            ids.chunks(chunk_size).map(<[i32]>::to_vec),
+            self.concurrency_limit(concurrency),
+            deadline,
+            f,
+        )
+        .await;
+
+        let mut results = ChunkedResults {
+            items: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        for (range, output) in ranges.into_iter().zip(outputs) {
+            match output {
+                Some(Ok(items)) => results.items.extend(items),
+                Some(Err(error)) => results.errors.push((range, error)),
+                None => results.errors.push((range, Error::DeadlineElapsed)),
+            }
+        }
+
+        results
+    }
+
+    /// Requests the projects in `project_ids` with [`Self::projects`], in
+    /// chunks of at most `chunk_size` IDs, with at most `concurrency` requests
+    /// in flight. See [`ChunkedResults`] for how a failed chunk is reported.
+    pub async fn projects_chunked<I, C>(
+        &self,
+        project_ids: I,
+        chunk_size: usize,
+        concurrency: C,
+    ) -> ChunkedResults<Project>
+    where
+        I: IntoIterator<Item = i32>,
+        C: Into<Concurrency>,
+    {
+        self.projects_chunked_until(project_ids, chunk_size, concurrency, None)
+            .await
+    }
+
+    /// Like [`Self::projects_chunked`], with a `deadline` for the whole batch,
+    /// see the [module documentation](self).
+    pub async fn projects_chunked_until<I, C>(
+        &self,
+        project_ids: I,
+        chunk_size: usize,
+        concurrency: C,
+        deadline: Option<Duration>,
+    ) -> ChunkedResults<Project>
+    where
+        I: IntoIterator<Item = i32>,
+        C: Into<Concurrency>,
+    {
+        let project_ids = project_ids.into_iter().collect();
+
+        self.chunked(
+            project_ids,
+            chunk_size,
+            concurrency.into(),
+            deadline,
+            |chunk| self.projects(chunk),
+        )
+        .await
+    }
+
+    /// Requests the files in `file_ids` with [`Self::project_files_by_ids`],
+    /// in chunks of at most `chunk_size` IDs, with at most `concurrency`
+    /// requests in flight. See [`ChunkedResults`] for how a failed chunk is
+    /// reported.
+    pub async fn project_files_by_ids_chunked<I, C>(
+        &self,
+        file_ids: I,
+        chunk_size: usize,
+        concurrency: C,
+    ) -> ChunkedResults<ProjectFile>
+    where
+        I: IntoIterator<Item = i32>,
+        C: Into<Concurrency>,
+    {
+        self.project_files_by_ids_chunked_until(file_ids, chunk_size, concurrency, None)
+            .await
+    }
+
+    /// Like [`Self::project_files_by_ids_chunked`], with a `deadline` for the
+    /// whole batch, see the [module documentation](self).
+    pub async fn project_files_by_ids_chunked_until<I, C>(
+        &self,
+        file_ids: I,
+        chunk_size: usize,
+        concurrency: C,
+        deadline: Option<Duration>,
+    ) -> ChunkedResults<ProjectFile>
+    where
+        I: IntoIterator<Item = i32>,
+        C: Into<Concurrency>,
+    {
+        let file_ids = file_ids.into_iter().collect();
+
+        self.chunked(
+            file_ids,
+            chunk_size,
+            concurrency.into(),
+            deadline,
+            |chunk| self.project_files_by_ids(chunk),
+        )
+        .await
+    }
+
+    /// Requests each game in `game_ids` concurrently, with at most
+    /// `concurrency` requests in flight. The API does not have an endpoint to
+    /// get several games at once, so this makes one request per ID.
+    ///
+    /// Every ID is paired with its own result, in the same order as the input.
+    pub async fn games_by_ids<I, C>(
+        &self,
+        game_ids: I,
+        concurrency: C,
+    ) -> Vec<(i32, Result<Game, Error>)>
+    where
+        I: IntoIterator<Item = i32>,
+        C: Into<Concurrency>,
+    {
+        self.games_by_ids_until(game_ids, concurrency, None).await
+    }
+
+    /// Like [`Self::games_by_ids`], with a `deadline` for the whole batch, see
+    /// the [module documentation](self).
+    pub async fn games_by_ids_until<I, C>(
+        &self,
+        game_ids: I,
+        concurrency: C,
+        deadline: Option<Duration>,
+    ) -> Vec<(i32, Result<Game, Error>)>
+    where
+        I: IntoIterator<Item = i32>,
+        C: Into<Concurrency>,
+    {
+        self.by_ids(game_ids, concurrency.into(), deadline, |game_id| {
+            self.game(game_id)
+        })
+        .await
+    }
+
+    /// Requests all of the categories of each game in `game_ids` concurrently,
+    /// with at most `concurrency` requests in flight. This makes one request
+    /// per ID, see [`CategoriesParams::game`].
+    ///
+    /// Every ID is paired with its own result, in the same order as the input.
+    pub async fn categories_for_games<I, C>(
+        &self,
+        game_ids: I,
+        concurrency: C,
+    ) -> Vec<(i32, Result<Vec<Category>, Error>)>
+    where
+        I: IntoIterator<Item = i32>,
+        C: Into<Concurrency>,
+    {
+        self.categories_for_games_until(game_ids, concurrency, None)
+            .await
+    }
+
+    /// Like [`Self::categories_for_games`], with a `deadline` for the whole
+    /// batch, see the [module documentation](self).
+    pub async fn categories_for_games_until<I, C>(
+        &self,
+        game_ids: I,
+        concurrency: C,
+        deadline: Option<Duration>,
+    ) -> Vec<(i32, Result<Vec<Category>, Error>)>
+    where
+        I: IntoIterator<Item = i32>,
+        C: Into<Concurrency>,
+    {
+        self.by_ids(
+            game_ids,
+            concurrency.into(),
+            deadline,
+            |game_id| async move { self.categories(&CategoriesParams::game(game_id)).await },
+        )
+        .await
+    }
+
+    /// Pages through every file of a project, lazily requesting the changelog
+    /// of each, with at most `concurrency` changelog requests in flight. The
+    /// files are yielded in the order that the API returns them, which is not
+    /// guaranteed, see [`Self::project_files_newest_first`].
+    pub fn project_changelog_stream<'cu, 'f, C>(
+        &'cu self,
+        project_id: i32,
+        concurrency: C,
+    ) -> ProjectChangelogStream<'f>
+    where
+        'cu: 'f,
+        C: Into<Concurrency>,
+    {
+        let concurrency = self.concurrency_limit(concurrency.into());
+        let files = self.project_files_iter(project_id, ProjectFilesParams::default());
+
+        Box::pin(
+            files
+                .map(move |result| async move {
+                    let file = result?;
+                    let changelog = self.project_file_changelog(project_id, file.id).await?;
+                    Ok((file, changelog))
+                })
+                .buffered(concurrency),
+        )
+    }
+}
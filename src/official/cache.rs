@@ -0,0 +1,133 @@
+//! Contains the [`GameMetadataCache`], which memoizes responses for game
+//! metadata that rarely changes.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::official::client::Client;
+use crate::official::request::CategoriesParams;
+use crate::official::types::{Category, GameVersionType, GameVersions};
+use crate::Error;
+
+/// A single memoized value, and the time at which it was retrieved.
+#[derive(Debug)]
+struct CacheEntry<T> {
+    value: T,
+    fetched: Instant,
+}
+
+/// Wraps a [`Client`] and memoizes the results of [`Client::game_versions`],
+/// [`Client::game_version_types`], and [`Client::categories`] per game ID.
+///
+/// Entries are considered fresh until the configured time-to-live has elapsed,
+/// after which the next access will make a new request. This structure is safe
+/// to share between threads, the maps are guarded by a [`Mutex`] which is never
+/// held across an `await`.
+#[derive(Debug)]
+pub struct GameMetadataCache {
+    client: Client,
+    ttl: Duration,
+    versions: Mutex<HashMap<i32, CacheEntry<Vec<GameVersions>>>>,
+    version_types: Mutex<HashMap<i32, CacheEntry<Vec<GameVersionType>>>>,
+    categories: Mutex<HashMap<i32, CacheEntry<Vec<Category>>>>,
+}
+
+impl GameMetadataCache {
+    /// Constructs a new, empty cache that will make requests with `client`,
+    /// and keep the responses for the duration of `ttl`.
+    pub fn new(client: Client, ttl: Duration) -> Self {
+        Self {
+            client,
+            ttl,
+            versions: Mutex::default(),
+            version_types: Mutex::default(),
+            categories: Mutex::default(),
+        }
+    }
+
+    /// Get an immutable borrow to the wrapped [`Client`].
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Get the time-to-live that entries are kept for.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Memoized version of [`Client::game_versions`].
+    pub async fn cached_game_versions(&self, game_id: i32) -> Result<Vec<GameVersions>, Error> {
+        self.get_or_fetch(&self.versions, game_id, || {
+            self.client.game_versions(game_id)
+        })
+        .await
+    }
+
+    /// Memoized version of [`Client::game_version_types`].
+    pub async fn cached_game_version_types(
+        &self,
+        game_id: i32,
+    ) -> Result<Vec<GameVersionType>, Error> {
+        self.get_or_fetch(&self.version_types, game_id, || {
+            self.client.game_version_types(game_id)
+        })
+        .await
+    }
+
+    /// Memoized version of [`Client::categories`], for every category of a
+    /// game (no `class_id` is provided).
+    pub async fn cached_categories(&self, game_id: i32) -> Result<Vec<Category>, Error> {
+        self.get_or_fetch(&self.categories, game_id, || async move {
+            self.client
+                .categories(&CategoriesParams::game(game_id))
+                .await
+        })
+        .await
+    }
+
+    /// Discard every cached value for a single game.
+    pub fn invalidate(&self, game_id: i32) {
+        self.versions.lock().unwrap().remove(&game_id);
+        self.version_types.lock().unwrap().remove(&game_id);
+        self.categories.lock().unwrap().remove(&game_id);
+    }
+
+    /// Discard every cached value for all games.
+    pub fn clear(&self) {
+        self.versions.lock().unwrap().clear();
+        self.version_types.lock().unwrap().clear();
+        self.categories.lock().unwrap().clear();
+    }
+
+    async fn get_or_fetch<T, F, Fut>(
+        &self,
+        map: &Mutex<HashMap<i32, CacheEntry<T>>>,
+        game_id: i32,
+        fetch: F,
+    ) -> Result<T, Error>
+    where
+        T: Clone,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        if let Some(entry) = map.lock().unwrap().get(&game_id) {
+            if entry.fetched.elapsed() < self.ttl {
+                return Ok(entry.value.clone());
+            }
+        }
+
+        let value = fetch().await?;
+
+        map.lock().unwrap().insert(
+            game_id,
+            CacheEntry {
+                value: value.clone(),
+                fetched: Instant::now(),
+            },
+        );
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,98 @@
+//! Contains [`Client::all_projects_for_game`], which works around the limit on
+//! the number of results of a single search.
+
+use std::collections::HashSet;
+
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+
+use crate::official::client::Client;
+use crate::official::endpoints::API_PAGINATION_RESULTS_LIMIT;
+use crate::official::request::pagination::pages;
+use crate::official::request::{
+    AllProjectsStream, CategoriesParams, ProjectSearchDelegate, ProjectSearchParams,
+};
+use crate::Error;
+
+impl Client {
+    /// Yields every project of a game, by splitting the catalog into searches
+    /// that each have fewer results than [`API_PAGINATION_RESULTS_LIMIT`].
+    ///
+    /// The catalog is first split by class (such as *Mods* or *Modpacks*).
+    /// Before any projects are yielded, one search is made for each class to
+    /// count its projects. A class with too many projects is split further
+    /// into one search for each of its categories. A project may be in
+    /// several categories, so the projects are deduplicated by ID, which keeps
+    /// the ID of every project yielded in memory.
+    ///
+    /// This is limited by the categories that the API provides, a category
+    /// that still has too many projects is only searched up to the limit, and
+    /// a project in a large class that has no category is not found. Projects
+    /// that are added or moved while the searches are running may be missed.
+    /// If a game has no classes, a single search is made for the whole game.
+    pub fn all_projects_for_game<'cu, 'f>(&'cu self, game_id: i32) -> AllProjectsStream<'f>
+    where
+        'cu: 'f,
+    {
+        let mut seen = HashSet::new();
+        let projects = stream::once(self.catalog_slices(game_id))
+            .map_ok(move |slices| {
+                stream::iter(slices)
+                    .map(move |params| {
+                        pages(ProjectSearchDelegate::new(
+                            self.inner(),
+                            self.base(),
+                            params,
+                        ))
+                    })
+                    .flatten()
+            })
+            .try_flatten()
+            .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+            .try_flatten()
+            .try_filter(move |project| std::future::ready(seen.insert(project.id)));
+
+        Box::pin(projects)
+    }
+
+    /// Splits the catalog of a game into searches that each have fewer results
+    /// than the limit, where possible.
+    async fn catalog_slices(&self, game_id: i32) -> Result<Vec<ProjectSearchParams>, Error> {
+        let categories = self.categories(&CategoriesParams::game(game_id)).await?;
+        let mut slices = Vec::new();
+
+        for class in categories
+            .iter()
+            .filter(|category| category.is_class == Some(true))
+        {
+            let mut params = ProjectSearchParams::game(game_id);
+            params.class_id = Some(class.id);
+
+            let mut count = params.clone();
+            count.page_size = Some(1);
+
+            let total = self.search_projects(&count).await?.pagination.total_count;
+
+            if total <= API_PAGINATION_RESULTS_LIMIT as i64 {
+                slices.push(params);
+                continue;
+            }
+
+            slices.extend(
+                categories
+                    .iter()
+                    .filter(|category| category.is_class != Some(true))
+                    .filter(|category| category.class_id == Some(class.id))
+                    .map(|category| ProjectSearchParams {
+                        category_id: Some(category.id),
+                        ..params.clone()
+                    }),
+            );
+        }
+
+        if slices.is_empty() {
+            slices.push(ProjectSearchParams::game(game_id));
+        }
+
+        Ok(slices)
+    }
+}
@@ -0,0 +1,130 @@
+//! Contains methods on [`Client`] and on [`ProjectFile`] for downloading the
+//! contents of a [`ProjectFile`], and the images of a [`Project`].
+
+use std::path::{Path, PathBuf};
+
+use futures_lite::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::official::client::Client;
+use crate::official::endpoints as e;
+use crate::official::types::{Project, ProjectAsset, ProjectFile};
+use crate::Error;
+
+impl Client {
+    /// Downloads the contents of `file` into `writer`, returning the number of
+    /// bytes that were written.
+    ///
+    /// The file is downloaded from its `download_url`, or if the API did not
+    /// provide one, from the URL built by [`e::construct_download_url`]. The
+    /// API token is not sent to the CDN.
+    ///
+    /// The writer is flushed and closed before this returns successfully, so
+    /// once it has, every byte has been handed to the writer's destination.
+    pub async fn download_file<W>(&self, file: &ProjectFile, writer: W) -> Result<u64, Error>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let uri = match file.resolved_download_url()? {
+            Some(uri) => uri,
+            None => e::construct_download_url(file.id, &file.file_name),
+        };
+
+        self.download_uri(uri, writer).await
+    }
+
+    /// Downloads the image of `asset`, such as a project's logo or one of its
+    /// screenshots, into `writer`, returning the number of bytes that were
+    /// written. This is `None` if the asset has no URL, in which case nothing
+    /// is written.
+    ///
+    /// As with [`Self::download_file`], the API token is not sent, and the
+    /// writer is flushed and closed before this returns successfully.
+    pub async fn download_asset<W>(
+        &self,
+        asset: &ProjectAsset,
+        writer: W,
+    ) -> Result<Option<u64>, Error>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        match &asset.url {
+            Some(url) => Ok(Some(
+                self.download_uri(url::Url::parse(url)?, writer).await?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Downloads the logo of `project` into memory, returning the bytes of the
+    /// image. This is `None` if the project has no logo, or if its logo has no
+    /// URL. See [`Self::download_asset`] to write a large image elsewhere
+    /// instead.
+    pub async fn project_logo(&self, project: &Project) -> Result<Option<Vec<u8>>, Error> {
+        let logo = match &project.logo {
+            Some(logo) => logo,
+            None => return Ok(None),
+        };
+        let mut bytes = Vec::new();
+
+        Ok(self.download_asset(logo, &mut bytes).await?.map(|_| bytes))
+    }
+
+    /// Sends a `GET` request to `uri` without the API token, and copies the
+    /// body of a successful response into `writer`.
+    async fn download_uri<W>(&self, uri: url::Url, mut writer: W) -> Result<u64, Error>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        use futures_lite::io::AsyncReadExt;
+
+        let response = self.downloader().get_async(uri.as_str()).await?;
+        let status = response.status();
+        let mut body = response.into_body();
+
+        if status != 200 {
+            let mut bytes = Vec::new();
+            body.read_to_end(&mut bytes).await?;
+
+            return Err(Error::from_status(uri, status, bytes));
+        }
+
+        let written = futures_lite::io::copy(body, &mut writer).await?;
+
+        writer.flush().await?;
+        writer.close().await?;
+
+        Ok(written)
+    }
+
+    /// Downloads the contents of `file` into a new file in `dir`, named with
+    /// [`ProjectFile::safe_filename`], and returns the path to it. An existing
+    /// file with the same name is replaced.
+    ///
+    /// The file is synced to the disk before this returns successfully, so the
+    /// complete contents may be read from the path immediately. If the
+    /// download fails, a partially written file may be left behind. The file
+    /// is written synchronously, see [`Self::download_file`] to provide an
+    /// asynchronous writer instead.
+    pub async fn download_file_to(&self, file: &ProjectFile, dir: &Path) -> Result<PathBuf, Error> {
+        let path = dir.join(file.safe_filename());
+        let handle = std::fs::File::create(&path)?;
+
+        self.download_file(file, futures_lite::io::AssertAsync::new(&handle))
+            .await?;
+        handle.sync_all()?;
+
+        Ok(path)
+    }
+}
+
+impl ProjectFile {
+    /// Downloads the contents of this file into `writer` with `client`,
+    /// returning the number of bytes that were written. This is the same as
+    /// [`Client::download_file`].
+    pub async fn download<W>(&self, client: &Client, writer: W) -> Result<u64, Error>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        client.download_file(self, writer).await
+    }
+}
@@ -0,0 +1,76 @@
+//! Contains the interceptor installed by [`ClientOptions::dump_responses`].
+//!
+//! [`ClientOptions::dump_responses`]: crate::official::client::ClientOptions::dump_responses
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use isahc::http::{Request, Response};
+use isahc::interceptor::{Context, Interceptor, InterceptorFuture};
+use isahc::AsyncBody;
+
+/// Writes the body of every response to a new file in `dir`, before handing
+/// the response back to the endpoint that made the request.
+///
+/// Files are named with the time that the response was received, a sequence
+/// number to keep concurrent responses apart, the method, and the path of the
+/// request, such as `20220301T120000.000000Z-0-GET-v1_mods_238222.json`.
+/// Failing to write a file never fails the request, this is a best-effort
+/// debugging aid.
+#[derive(Debug)]
+pub(crate) struct ResponseDumper {
+    dir: PathBuf,
+    sequence: AtomicU64,
+}
+
+impl ResponseDumper {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            sequence: AtomicU64::new(0),
+        }
+    }
+
+    fn file_name<T>(&self, request: &Request<T>) -> String {
+        let timestamp = chrono::Utc::now().format("%Y%m%dT%H%M%S%.6fZ");
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+        let endpoint = request
+            .uri()
+            .path()
+            .trim_matches('/')
+            .replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "_");
+
+        format!(
+            "{}-{}-{}-{}.json",
+            timestamp,
+            sequence,
+            request.method(),
+            endpoint
+        )
+    }
+}
+
+impl Interceptor for ResponseDumper {
+    type Err = isahc::Error;
+
+    fn intercept<'a>(
+        &'a self,
+        request: Request<AsyncBody>,
+        ctx: Context<'a>,
+    ) -> InterceptorFuture<'a, Self::Err> {
+        Box::pin(async move {
+            use futures_lite::io::AsyncReadExt;
+
+            let file_name = self.file_name(&request);
+            let (parts, mut body) = ctx.send(request).await?.into_parts();
+            let mut bytes = Vec::new();
+
+            body.read_to_end(&mut bytes).await?;
+
+            let _ = std::fs::create_dir_all(&self.dir)
+                .and_then(|_| std::fs::write(self.dir.join(file_name), &bytes));
+
+            Ok(Response::from_parts(parts, AsyncBody::from(bytes)))
+        })
+    }
+}
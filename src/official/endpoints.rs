@@ -1,9 +1,12 @@
 //! Contains methods that take an [`isahc::HttpClient`] and make a request
 //! to a CurseForge endpoint.
 
+use chrono::{DateTime, Utc};
+
 use crate::official::request::pagination::{
-    GamesDelegate, GamesStream, ProjectFilesDelegate, ProjectFilesStream, ProjectSearchDelegate,
-    ProjectSearchStream,
+    CategoriesStream, CheckpointedSearchStream, GamesDelegate, GamesStream, ProjectFilesDelegate,
+    ProjectFilesStream, ProjectSearchDelegate, ProjectSearchStream, ProjectSummaryStream,
+    ScanCheckpoint,
 };
 use crate::official::request::params::{
     several_body, CategoriesParams, FeaturedProjectsBody, GamesParams, ProjectFilesParams,
@@ -11,54 +14,369 @@ use crate::official::request::params::{
 };
 use crate::official::request::{ApiDataResult, ApiPageResult, ApiResponse, DataResponse};
 use crate::official::types::{
-    Category, FeaturedProjects, Game, GameVersionType, GameVersions, Project, ProjectFile,
+    Category, FeaturedProjects, FingerprintsMatchesResult, Game, GameVersionType, GameVersions,
+    MinecraftGameVersion, MinecraftModLoaderIndex, MinecraftModLoaderVersion, Project, ProjectFile,
+    ProjectSummary,
 };
+use crate::official::unknown_fields::LogUnknownFields;
 use crate::Error;
 
 /// This is the official CurseForge Core API base URL.
 /// You must pass it to constructors explicitly.
 pub static DEFAULT_API_BASE: &str = "https://api.curseforge.com/v1/";
+/// This is the base URL of the CDN that project files are downloaded from.
+/// Relative download URLs are resolved against it, see
+/// [`resolve_download_url`].
+pub static DEFAULT_CDN_BASE: &str = "https://edge.forgecdn.net/";
+
+/// Resolves a download URL returned by the API to an absolute URL. Some
+/// proxies rewrite download URLs to a path relative to the CDN, these are
+/// joined to [`DEFAULT_CDN_BASE`]. Absolute URLs are returned as they are.
+pub fn resolve_download_url(download_url: &str) -> Result<url::Url, url::ParseError> {
+    // Use of unwrap: the constant is known to be a valid URL.
+    url::Url::parse(DEFAULT_CDN_BASE)
+        .unwrap()
+        .join(download_url)
+}
+
+/// Splits a file ID into the two numeric path segments that the CDN stores the
+/// file under, which are the ID divided by 1000, and the remainder. The
+/// remainder is not padded, so file `4593548` is at `/files/4593/548/`, and
+/// file `3000012` is at `/files/3000/12/`.
+pub fn cdn_path_components(file_id: i32) -> (i32, i32) {
+    (file_id / 1000, file_id % 1000)
+}
+
+/// Constructs the URL that a file would be downloaded from on the CDN, for
+/// files whose `download_url` is not provided by the API. The `file_name` is
+/// percent-encoded as a single path segment. See [`cdn_path_components`].
+pub fn construct_download_url(file_id: i32, file_name: &str) -> url::Url {
+    let (head, tail) = cdn_path_components(file_id);
+    // Use of unwrap: the constant is known to be a valid URL.
+    let mut url = url::Url::parse(DEFAULT_CDN_BASE).unwrap();
+
+    // Use of unwrap: the URL is known to have a base, because it has a host.
+    url.path_segments_mut().unwrap().pop_if_empty().extend([
+        "files",
+        &head.to_string(),
+        &tail.to_string(),
+        file_name,
+    ]);
+
+    url
+}
+
+/// The known hosts of the CurseForge Core API, including the community proxies
+/// that do not require a token. Each of these has a different path prefix
+/// before the endpoint paths. See [`normalize_base`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProxyKind {
+    /// The official API at `api.curseforge.com`, which requires a token.
+    Official,
+    /// The proxy at `api.curse.tools`, which nests the API under `/v1/cf/`.
+    CurseTools,
+    /// The proxy at `cfproxy.fly.dev`, which mirrors the official paths.
+    CfProxy,
+}
+
+impl ProxyKind {
+    /// Returns the complete API base URL for this host.
+    pub fn api_base(&self) -> &'static str {
+        match self {
+            Self::Official => DEFAULT_API_BASE,
+            Self::CurseTools => "https://api.curse.tools/v1/cf/",
+            Self::CfProxy => "https://cfproxy.fly.dev/v1/",
+        }
+    }
+
+    /// Determines which of the known hosts a URL refers to, if any.
+    pub fn detect(url: &url::Url) -> Option<Self> {
+        match url.host_str()? {
+            "api.curseforge.com" => Some(Self::Official),
+            "api.curse.tools" => Some(Self::CurseTools),
+            "cfproxy.fly.dev" => Some(Self::CfProxy),
+            _ => None,
+        }
+    }
+}
+
+/// Parses and normalizes an API base URL so that endpoint paths can be joined
+/// to it uniformly. This is used by [`Client::new`].
+///
+/// - If the URL is only the origin of one of the [`ProxyKind`] hosts, such as `https://api.curse.tools`,
+///   the path prefix for that host is added. The rest of the URL, such as the
+///   scheme and port, is kept.
+/// - A trailing slash is added to the path if it is missing. Without it,
+///   [`url::Url::join`] would replace the last segment rather than append to
+///   it, such that `https://api.curse.tools/v1/cf` would resolve endpoints
+///   under `/v1/` instead of `/v1/cf/`.
+///
+/// [`Client::new`]: crate::official::client::Client::new
+pub fn normalize_base(base: &str) -> Result<url::Url, Error> {
+    let mut url = url::Url::parse(base)?;
+
+    if url.cannot_be_a_base() {
+        return Err(Error::BadBaseUrl);
+    }
+
+    if let Some(kind) = ProxyKind::detect(&url) {
+        if url.path() == "/" {
+            // Use of unwrap: the constants are known to be valid URLs.
+            url.set_path(url::Url::parse(kind.api_base()).unwrap().path());
+        }
+    }
+
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+
+    Ok(url)
+}
+
+/// A version of the CurseForge Core API, which is the first segment of the
+/// path of every endpoint, such as `/v1/mods/search`.
+///
+/// Endpoints are joined to the client's base URL, which includes the version
+/// segment for [`ApiVersion::V1`]. A client sends every request to another
+/// version when it is built with [`ClientBuilder::api_version`], which
+/// replaces that segment with [`versioned_base`].
+///
+/// [`ClientBuilder::api_version`]: crate::official::client::ClientBuilder::api_version
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    /// The version that every endpoint of this crate currently uses.
+    V1,
+    /// The version that some newer endpoints of the API are under.
+    V2,
+}
+
+impl ApiVersion {
+    /// Returns the path segment for this version, such as `v1`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::V1 => "v1",
+            Self::V2 => "v2",
+        }
+    }
+}
+
+/// Returns a copy of a normalized base URL that targets `version` of the API,
+/// by replacing the last segment of the path that names a version, such as
+/// `v1`. This keeps the path prefix of proxies, so that
+/// `https://api.curse.tools/v1/cf/` becomes `https://api.curse.tools/v2/cf/`.
+///
+/// If no segment of the path names a version, the version that the base
+/// targets is not known, and [`Error::BadBaseUrl`] is returned.
+pub fn versioned_base(base: &url::Url, version: ApiVersion) -> Result<url::Url, Error> {
+    let is_version = |segment: &str| {
+        segment
+            .strip_prefix('v')
+            .is_some_and(|number| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+    };
+
+    let mut segments = base
+        .path_segments()
+        .ok_or(Error::BadBaseUrl)?
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+    let index = segments
+        .iter()
+        .rposition(|segment| is_version(segment))
+        .ok_or(Error::BadBaseUrl)?;
+
+    segments[index] = version.as_str().to_owned();
+
+    let mut url = base.clone();
+    url.set_path(&segments.join("/"));
+    Ok(url)
+}
+
 /// The CurseForge API has a maximum limit of 10,000 results that can be
 /// returned from any paginated request. Refer to the
 /// [documentation](https://docs.curseforge.com/#pagination-limits) for more information.
 pub const API_PAGINATION_RESULTS_LIMIT: usize = 10_000;
 
+/// The text that the challenge pages of Cloudflare are known to contain.
+const CLOUDFLARE_CHALLENGE_MARKERS: &[&str] = &[
+    "challenge-platform",
+    "cf-chl-",
+    "<title>Just a moment...</title>",
+    "<title>Attention Required! | Cloudflare</title>",
+];
+
+/// Returns `true` if a response was a challenge or block page sent by
+/// Cloudflare, which the API and some proxies are served behind, rather than a
+/// response from the API itself. This is checked by every endpoint function,
+/// which returns [`Error::Blocked`] for such a response, whatever its status.
+///
+/// A response is a challenge if it has the header `cf-mitigated: challenge`.
+/// Otherwise, it must have been served by Cloudflare, be HTML, and contain one
+/// of the markers of a challenge page.
+pub fn is_cloudflare_challenge(headers: &isahc::http::HeaderMap, bytes: &[u8]) -> bool {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+    };
+
+    if header("cf-mitigated").eq_ignore_ascii_case("challenge") {
+        return true;
+    }
+
+    if !header("server").eq_ignore_ascii_case("cloudflare")
+        || !header("content-type").starts_with("text/html")
+    {
+        return false;
+    }
+
+    let body = String::from_utf8_lossy(bytes);
+
+    CLOUDFLARE_CHALLENGE_MARKERS
+        .iter()
+        .any(|marker| body.contains(marker))
+}
+
+/// Deserializes the body of a successful response from the API, in the same
+/// way as every endpoint function. This is exposed so that saved responses can
+/// be checked without making a request. The `uri` is only used for errors.
+///
+/// If the body fails to deserialize because the API sent `"data": null`,
+/// which it does for some resources that do not exist, this returns
+/// [`Error::NotFound`] instead of [`Error::Deserialize`]. If it fails because
+/// the body is not valid UTF-8, this returns [`Error::InvalidUtf8`].
+pub fn decode<T>(uri: url::Url, bytes: &[u8]) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    decode_with(uri, bytes, false)
+}
+
+/// The same as [`decode`], except that when `log_unknown_fields` is `true`,
+/// the path to every field of the body that was not deserialized is logged,
+/// see [`ClientOptions::log_unknown_fields`].
+///
+/// [`ClientOptions::log_unknown_fields`]: crate::official::client::ClientOptions::log_unknown_fields
+pub(crate) fn decode_with<T>(
+    uri: url::Url,
+    bytes: &[u8],
+    log_unknown_fields: bool,
+) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    #[derive(serde::Deserialize)]
+    struct DataProbe {
+        data: Option<serde::de::IgnoredAny>,
+    }
+
+    let deser = &mut serde_json::Deserializer::from_slice(bytes);
+    let result = if log_unknown_fields {
+        let mut unknown = Vec::new();
+        let mut record = |path: serde_ignored::Path<'_>| unknown.push(path.to_string());
+        let result =
+            serde_path_to_error::deserialize(serde_ignored::Deserializer::new(deser, &mut record));
+
+        for field in unknown {
+            tracing::warn!(%uri, %field, "the API responded with an unknown field");
+        }
+
+        result
+    } else {
+        serde_path_to_error::deserialize(deser)
+    };
+
+    result.map_err(|error| {
+        if let Err(error) = std::str::from_utf8(bytes) {
+            return Error::InvalidUtf8 {
+                uri,
+                error,
+                bytes: Box::new(bytes.to_vec()),
+            };
+        }
+
+        match serde_json::from_slice::<DataProbe>(bytes) {
+            Ok(DataProbe { data: None }) => Error::NotFound { uri },
+            _ => Error::Deserialize {
+                uri,
+                error,
+                bytes: Box::new(bytes.to_vec()),
+            },
+        }
+    })
+}
+
 macro_rules! endpoint {
     (
         $client:ident $method:ident,
         uri: $base:ident / $path:literal,
         $(vars: [$($var:ident),+],)?
         $(params: $params:expr,)?
+        $(query: $query:expr,)?
         $(body: $body:expr,)?
+        $(headers: [$($header:expr => $value:expr),+],)?
+        $(empty: $empty:expr,)?
     ) => {{
         use futures_lite::io::AsyncReadExt;
 
         #[allow(unused_mut)]
         let mut uri = endpoint!(@uri, $base, $path $(, [$($var),*])?);
         $(uri.set_query(Some(&serde_qs::to_string($params).unwrap()));)?
+        $(uri.set_query(Some(&$query));)?
 
         let builder = isahc::Request::builder()
             .method(endpoint!(@str $method))
             .uri(uri.as_str());
+        $($(let builder = builder.header($header, $value);)+)?
         let request = endpoint!(@build, builder $(, $body)?)?;
 
+        let started = std::time::Instant::now();
         let response = $client.send_async(request).await?;
         let status = response.status();
+        let (parts, mut body) = response.into_parts();
         let mut bytes = Vec::new();
 
-        response.into_body().read_to_end(&mut bytes).await.unwrap();
+        body.read_to_end(&mut bytes).await?;
+
+        let elapsed = started.elapsed();
 
-        if status != 200 {
-            return Err(Error::StatusNotOk { uri, status, bytes: Box::new(bytes) });
+        if is_cloudflare_challenge(&parts.headers, &bytes) {
+            return Err(Error::Blocked { uri });
         }
 
-        let deser = &mut serde_json::Deserializer::from_slice(bytes.as_slice());
-        let result = serde_path_to_error::deserialize(deser);
+        $(
+            // Some proxies respond to requests that have no results with an
+            // empty body, rather than an empty list.
+            if (status == 200 || status == 204) && bytes.iter().all(u8::is_ascii_whitespace) {
+                let value = DataResponse::new($empty);
+
+                return Ok(ApiResponse {
+                    bytes,
+                    value,
+                    elapsed,
+                    headers: parts.headers,
+                });
+            }
+        )?
 
-        match result {
-            Ok(value) => Ok(ApiResponse { bytes, value }),
-            Err(error) => Err(Error::Deserialize { uri, error, bytes: Box::new(bytes) }),
+        // The body of any other status is an error page, which is returned
+        // as it is rather than being parsed as JSON.
+        if !status.is_success() {
+            return Err(Error::from_status(uri, status, bytes));
         }
+
+        let log_unknown_fields = parts.extensions.get::<LogUnknownFields>().is_some();
+        let value = decode_with(uri, &bytes, log_unknown_fields)?;
+
+        Ok(ApiResponse {
+            bytes,
+            value,
+            elapsed,
+            headers: parts.headers,
+        })
     }};
     (@uri, $base:ident, $path:literal) => {
         $base.join($path).unwrap()
@@ -116,6 +434,8 @@ pub fn games_iter<'cu, 'f>(
 }
 
 /// <https://docs.curseforge.com/#get-versions>
+///
+/// An empty response body is treated as an empty list.
 pub async fn game_versions(
     client: &isahc::HttpClient,
     base: &url::Url,
@@ -125,10 +445,71 @@ pub async fn game_versions(
         client GET,
         uri: base / "games/{}/versions",
         vars: [game_id],
+        empty: Vec::new(),
+    }
+}
+
+/// <https://docs.curseforge.com/#get-minecraft-versions>
+///
+/// These are the versions of Minecraft, with the URLs to download each.
+/// An empty response body is treated as an empty list.
+pub async fn minecraft_versions(
+    client: &isahc::HttpClient,
+    base: &url::Url,
+) -> ApiDataResult<Vec<MinecraftGameVersion>> {
+    endpoint! {
+        client GET,
+        uri: base / "minecraft/version",
+        empty: Vec::new(),
+    }
+}
+
+/// <https://docs.curseforge.com/#get-minecraft-modloaders>
+///
+/// These are the latest and the recommended version of each mod loader, for
+/// each version of Minecraft. An empty response body is treated as an empty
+/// list.
+pub async fn minecraft_modloaders(
+    client: &isahc::HttpClient,
+    base: &url::Url,
+) -> ApiDataResult<Vec<MinecraftModLoaderIndex>> {
+    endpoint! {
+        client GET,
+        uri: base / "minecraft/modloader",
+        empty: Vec::new(),
+    }
+}
+
+/// <https://docs.curseforge.com/#get-specific-minecraft-modloader>
+///
+/// The `name` is that of a [`MinecraftModLoaderIndex`], such as
+/// `forge-47.2.0`. It is percent-encoded as a single path segment, and a name
+/// that is empty, `.`, or `..` is rejected with [`Error::InvalidParams`].
+pub async fn minecraft_modloader(
+    client: &isahc::HttpClient,
+    base: &url::Url,
+    name: &str,
+) -> ApiDataResult<MinecraftModLoaderVersion> {
+    use percent_encoding::utf8_percent_encode;
+
+    if matches!(name, "" | "." | "..") {
+        return Err(Error::InvalidParams(
+            "the name of a mod loader must not be empty, `.`, or `..`",
+        ));
+    }
+
+    let name = utf8_percent_encode(name, crate::PATH_SEGMENT);
+
+    endpoint! {
+        client GET,
+        uri: base / "minecraft/modloader/{}",
+        vars: [name],
     }
 }
 
 /// <https://docs.curseforge.com/#get-version-types>
+///
+/// An empty response body is treated as an empty list.
 pub async fn game_version_types(
     client: &isahc::HttpClient,
     base: &url::Url,
@@ -138,32 +519,96 @@ pub async fn game_version_types(
         client GET,
         uri: base / "games/{}/version-types",
         vars: [game_id],
+        empty: Vec::new(),
     }
 }
 
 /// <https://docs.curseforge.com/#get-categories>
+///
+/// If `params.parent_category_id` is set, the categories in the response are
+/// filtered after it has been received. See [`CategoriesParams`]. An empty
+/// response body is treated as an empty list.
 pub async fn categories(
     client: &isahc::HttpClient,
     base: &url::Url,
     params: &CategoriesParams,
 ) -> ApiDataResult<Vec<Category>> {
-    endpoint! {
+    let result: ApiDataResult<Vec<Category>> = endpoint! {
         client GET,
         uri: base / "categories",
         params: params,
-    }
+        empty: Vec::new(),
+    };
+
+    result.map(|mut r| {
+        if let Some(parent_category_id) = params.parent_category_id {
+            r.value
+                .data
+                .retain(|category| category.parent_category_id == Some(parent_category_id));
+        }
+        r
+    })
+}
+
+/// <https://docs.curseforge.com/#get-categories>
+///
+/// See the documentation for [`CategoriesStream`].
+pub fn categories_stream<'cu, 'f>(
+    client: &'cu isahc::HttpClient,
+    base: &'cu url::Url,
+    params: CategoriesParams,
+) -> CategoriesStream<'f>
+where
+    'cu: 'f,
+{
+    use futures_lite::{stream, StreamExt};
+
+    Box::pin(
+        stream::once_future(async move { categories(client, base, &params).await }).flat_map(
+            |result| {
+                let items = match result {
+                    Ok(r) => r.into_value().data.into_iter().map(Ok).collect(),
+                    Err(error) => vec![Err(error)],
+                };
+                stream::iter(items)
+            },
+        ),
+    )
 }
 
 /// <https://docs.curseforge.com/#search-mods>
+///
+/// The parameters are checked with [`ProjectSearchParams::validate`] before
+/// the request is sent.
 pub async fn search_projects(
     client: &isahc::HttpClient,
     base: &url::Url,
     params: &ProjectSearchParams,
 ) -> ApiPageResult<Project> {
+    params.validate()?;
+
     endpoint! {
         client GET,
         uri: base / "mods/search",
-        params: params,
+        query: params.query_string(),
+    }
+}
+
+/// <https://docs.curseforge.com/#search-mods>
+///
+/// Sends `query` as the query string exactly as it is given, such as one
+/// copied from a log or from the examples in the documentation. A leading `?`
+/// is ignored. This bypasses [`ProjectSearchParams::validate`], so any
+/// mistake in the query is only reported by the API, if at all.
+pub async fn search_projects_raw_query(
+    client: &isahc::HttpClient,
+    base: &url::Url,
+    query: &str,
+) -> ApiPageResult<Project> {
+    endpoint! {
+        client GET,
+        uri: base / "mods/search",
+        query: query.strip_prefix('?').unwrap_or(query),
     }
 }
 
@@ -180,6 +625,64 @@ pub fn search_projects_iter<'cu, 'f>(
     ProjectSearchDelegate::new(client, base, params).into()
 }
 
+/// <https://docs.curseforge.com/#search-mods>
+///
+/// This is the same as [`search_projects_iter`], except that the first page
+/// will be requested from `start_index` rather than from the `index` in
+/// `params`. This can be used to resume a scan from a checkpoint, where the
+/// index to resume from is `start_index` plus the number of items that were
+/// yielded from the previous stream.
+pub fn search_projects_iter_from<'cu, 'f>(
+    client: &'cu isahc::HttpClient,
+    base: &'cu url::Url,
+    mut params: ProjectSearchParams,
+    start_index: i32,
+) -> ProjectSearchStream<'cu, 'f> {
+    params.index = Some(start_index);
+    ProjectSearchDelegate::new(client, base, params).into()
+}
+
+/// <https://docs.curseforge.com/#search-mods>
+///
+/// This is the same as [`search_projects_iter_from`], except that the position
+/// to resume from is a [`ScanCheckpoint`] that was taken from a stream with
+/// [`CheckpointedSearchStream::checkpoint`], and may have been stored in
+/// between. A new scan is started from [`ScanCheckpoint::default`]. If the
+/// checkpoint shows that the scan had already reached the last result, the
+/// stream ends without making any requests.
+pub fn search_projects_iter_from_checkpoint<'cu, 'f>(
+    client: &'cu isahc::HttpClient,
+    base: &'cu url::Url,
+    params: ProjectSearchParams,
+    checkpoint: ScanCheckpoint,
+) -> CheckpointedSearchStream<'f>
+where
+    'cu: 'f,
+{
+    CheckpointedSearchStream::new(client, base, params, checkpoint)
+}
+
+/// <https://docs.curseforge.com/#search-mods>
+///
+/// This is the same as [`search_projects_iter`], except that every project is
+/// immediately converted into a [`ProjectSummary`], so that the heavier fields
+/// are not retained in memory.
+pub fn search_projects_lite<'cu, 'f>(
+    client: &'cu isahc::HttpClient,
+    base: &'cu url::Url,
+    params: ProjectSearchParams,
+) -> ProjectSummaryStream<'cu, 'f>
+where
+    'cu: 'f,
+{
+    use futures_lite::StreamExt;
+
+    let summarize: fn(Result<Project, Error>) -> Result<ProjectSummary, Error> =
+        |result| result.map(ProjectSummary::from);
+
+    search_projects_iter(client, base, params).map(summarize)
+}
+
 /// <https://docs.curseforge.com/#get-mod>
 ///
 /// Renamed from `mod` to `project` because the former is a keyword, and the
@@ -196,7 +699,47 @@ pub async fn project(
     }
 }
 
+/// <https://docs.curseforge.com/#get-mod>
+///
+/// Requests a project only if it has been modified after `since`, sending the
+/// request with an `If-Modified-Since` header. This returns `None` when the
+/// API responds with `304: Not Modified`, and also when the `date_modified`
+/// of the project that was received is not after `since`. The second check
+/// is needed because the API may ignore the header, and because a client with
+/// a [`ResponseCache`] turns a `304` into the cached response.
+///
+/// [`ResponseCache`]: crate::official::http_cache::ResponseCache
+pub async fn project_if_modified_since(
+    client: &isahc::HttpClient,
+    base: &url::Url,
+    project_id: i32,
+    since: DateTime<Utc>,
+) -> Result<Option<ApiResponse<DataResponse<Project>>>, Error> {
+    use isahc::http::header::IF_MODIFIED_SINCE;
+    use isahc::http::StatusCode;
+
+    let since_header = since.format("%a, %d %b %Y %H:%M:%S GMT").to_string();
+    let result: ApiDataResult<Project> = async {
+        endpoint! {
+            client GET,
+            uri: base / "mods/{}",
+            vars: [project_id],
+            headers: [IF_MODIFIED_SINCE => since_header],
+        }
+    }
+    .await;
+
+    match result {
+        Ok(response) if response.value.data.date_modified > since => Ok(Some(response)),
+        Ok(_) => Ok(None),
+        Err(Error::StatusNotOk { status, .. }) if status == StatusCode::NOT_MODIFIED => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
 /// <https://docs.curseforge.com/#get-mods>
+///
+/// An empty response body is treated as an empty list.
 pub async fn projects<I>(
     client: &isahc::HttpClient,
     base: &url::Url,
@@ -209,6 +752,7 @@ where
         client POST,
         uri: base / "mods",
         body: &several_body!("modIds", i32, project_ids.into_iter()),
+        empty: Vec::new(),
     }
 }
 
@@ -254,23 +798,30 @@ pub async fn project_file(
 
 /// Alternative method to [`project_file`] that eliminates the need
 /// for a `project_id`. This uses [`project_files_by_ids`] and
-/// returns the only item.
+/// returns the only item, or [`Error::NotFound`] if there is none.
 pub async fn project_file_by_id(
     client: &isahc::HttpClient,
     base: &url::Url,
     file_id: i32,
 ) -> ApiDataResult<ProjectFile> {
-    project_files_by_ids(client, base, [file_id])
-        .await
-        .map(|mut r| ApiResponse {
-            bytes: r.bytes,
-            // Use of unwrap: if no item were present the bytes would be empty (parse error)
-            value: DataResponse {
-                data: r.value.data.pop().unwrap(),
-                #[cfg(feature = "allow-unknown-fields")]
-                other_fields: r.value.other_fields,
-            },
-        })
+    let mut r = project_files_by_ids(client, base, [file_id]).await?;
+
+    // The list may be empty if the file does not exist, see
+    // `project_files_by_ids`.
+    let data = r.value.data.pop().ok_or_else(|| Error::NotFound {
+        uri: base.join("mods/files").unwrap(),
+    })?;
+
+    Ok(ApiResponse {
+        bytes: r.bytes,
+        elapsed: r.elapsed,
+        headers: r.headers,
+        value: DataResponse {
+            data,
+            #[cfg(feature = "allow-unknown-fields")]
+            other_fields: r.value.other_fields,
+        },
+    })
 }
 
 /// <https://docs.curseforge.com/#get-mod-files>
@@ -293,16 +844,60 @@ pub async fn project_files(
 /// This adheres to the limit of results defined by the
 /// [documentation](https://docs.curseforge.com/#pagination-limits),
 /// hardcoded by the constant [`API_PAGINATION_RESULTS_LIMIT`].
+///
+/// The API has no filter for the release type, so if
+/// [`ProjectFilesParams::release_type`] is set, files of other release types
+/// are discarded from each page here instead.
 pub fn project_files_iter<'cu, 'f>(
     client: &'cu isahc::HttpClient,
     base: &'cu url::Url,
     project_id: i32,
     params: ProjectFilesParams,
-) -> ProjectFilesStream<'cu, 'f> {
-    ProjectFilesDelegate::new(client, base, project_id, params).into()
+) -> ProjectFilesStream<'cu, 'f>
+where
+    'cu: 'f,
+{
+    use awaur::paginator::PaginatedStream;
+    use futures_lite::StreamExt;
+
+    let release_type = params.release_type.clone();
+    let files: PaginatedStream<'f, _> =
+        ProjectFilesDelegate::new(client, base, project_id, params).into();
+
+    match release_type {
+        Some(release_type) => Box::pin(files.filter(
+            move |result| !matches!(result, Ok(file) if file.release_type != release_type),
+        )),
+        None => Box::pin(files),
+    }
+}
+
+/// <https://docs.curseforge.com/#get-mod-files>
+///
+/// This is the same as [`project_files_iter`], except that the first page
+/// will be requested from `start_index` rather than from the `index` in
+/// `params`. This can be used to resume a listing from a checkpoint, where the
+/// index to resume from is `start_index` plus the number of items that were
+/// yielded from the previous stream. When `params.release_type` is set, the
+/// files that were skipped must be counted as well, so the checkpoint should be
+/// taken from a stream without it.
+pub fn project_files_iter_from<'cu, 'f>(
+    client: &'cu isahc::HttpClient,
+    base: &'cu url::Url,
+    project_id: i32,
+    mut params: ProjectFilesParams,
+    start_index: i32,
+) -> ProjectFilesStream<'cu, 'f>
+where
+    'cu: 'f,
+{
+    params.index = Some(start_index);
+    project_files_iter(client, base, project_id, params)
 }
 
 /// <https://docs.curseforge.com/#get-files>
+///
+/// An empty response body is treated as an empty list.
 pub async fn project_files_by_ids<I>(
     client: &isahc::HttpClient,
     base: &url::Url,
@@ -315,6 +910,51 @@ where
         client POST,
         uri: base / "mods/files",
         body: &several_body!("fileIds", i32, file_ids.into_iter()),
+        empty: Vec::new(),
+    }
+}
+
+/// <https://docs.curseforge.com/#get-fingerprints-matches>
+///
+/// Matches the MurmurHash2 `fingerprints` of files against the files of every
+/// game, see [`ProjectFile::file_fingerprint`]. This identifies files without
+/// knowing their projects, such as the jars installed in a modpack.
+pub async fn fingerprint_matches<I>(
+    client: &isahc::HttpClient,
+    base: &url::Url,
+    fingerprints: I,
+) -> ApiDataResult<FingerprintsMatchesResult>
+where
+    I: IntoIterator<Item = i64>,
+{
+    endpoint! {
+        client POST,
+        uri: base / "fingerprints",
+        body: &several_body!("fingerprints", i64, fingerprints.into_iter()),
+    }
+}
+
+/// <https://docs.curseforge.com/#get-fingerprints-matches-by-game-id>
+///
+/// Matches the MurmurHash2 `fingerprints` of files against the files of a
+/// single game. The same file may be uploaded to projects of several games, so
+/// whenever the game is known, such as when identifying the contents of an
+/// instance of that game, this should be preferred over
+/// [`fingerprint_matches`], which may return a match from the wrong game.
+pub async fn fingerprint_matches_for_game<I>(
+    client: &isahc::HttpClient,
+    base: &url::Url,
+    game_id: i32,
+    fingerprints: I,
+) -> ApiDataResult<FingerprintsMatchesResult>
+where
+    I: IntoIterator<Item = i64>,
+{
+    endpoint! {
+        client POST,
+        uri: base / "fingerprints/{}",
+        vars: [game_id],
+        body: &several_body!("fingerprints", i64, fingerprints.into_iter()),
     }
 }
 
@@ -333,6 +973,12 @@ pub async fn project_file_changelog(
 }
 
 /// <https://docs.curseforge.com/#get-mod-file-download-url>
+///
+/// The URL is returned exactly as the API provided it, which may be relative
+/// when using some proxies. [`Client::project_file_download_url`] resolves it
+/// with [`resolve_download_url`].
+///
+/// [`Client::project_file_download_url`]: crate::official::client::Client::project_file_download_url
 pub async fn project_file_download_url(
     client: &isahc::HttpClient,
     base: &url::Url,
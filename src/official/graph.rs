@@ -0,0 +1,161 @@
+//! Contains [`FileGraph`], which links the files of a project by the
+//! relationships that are otherwise scattered across [`ProjectFile`] fields.
+
+use std::collections::BTreeMap;
+
+use futures_util::TryStreamExt;
+
+use crate::official::client::Client;
+use crate::official::request::pagination::pages;
+use crate::official::request::{ProjectFilesDelegate, ProjectFilesParams};
+use crate::official::types::ProjectFile;
+use crate::Error;
+
+/// The field of a [`ProjectFile`] that a [`FileEdge`] was made from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FileRelation {
+    /// From [`ProjectFile::parent_project_file_id`], which a server pack uses
+    /// to refer to the file that it was made for.
+    Parent,
+    /// From [`ProjectFile::alternate_file_id`].
+    Alternate,
+    /// From [`ProjectFile::server_pack_file_id`].
+    ServerPack,
+}
+
+/// A reference from the file with the ID `from` to the file with the ID `to`.
+/// The file that is referred to may not be in the graph, such as when it has
+/// been deleted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FileEdge {
+    pub from: i32,
+    pub to: i32,
+    pub relation: FileRelation,
+}
+
+/// The files of a project, along with an edge for every reference that one
+/// file makes to another. See [`Client::file_relationship_graph`].
+///
+/// Some references are only made in one direction, so the traversal helpers
+/// follow edges both ways. A reference to the ID `0` is treated as no
+/// reference.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileGraph {
+    files: BTreeMap<i32, ProjectFile>,
+    edges: Vec<FileEdge>,
+}
+
+impl FileGraph {
+    /// Builds the graph of `files`, which are expected to be from the same
+    /// project. A file that appears more than once replaces the earlier one.
+    pub fn new<I>(files: I) -> Self
+    where
+        I: IntoIterator<Item = ProjectFile>,
+    {
+        let files = files
+            .into_iter()
+            .map(|file| (file.id, file))
+            .collect::<BTreeMap<_, _>>();
+        let edges = files
+            .values()
+            .flat_map(|file| {
+                [
+                    (file.parent_project_file_id, FileRelation::Parent),
+                    (file.alternate_file_id, FileRelation::Alternate),
+                    (file.server_pack_file_id, FileRelation::ServerPack),
+                ]
+                .into_iter()
+                .filter_map(|(to, relation)| {
+                    Some(FileEdge {
+                        from: file.id,
+                        to: to.filter(|to| *to != 0)?,
+                        relation,
+                    })
+                })
+            })
+            .collect();
+
+        Self { files, edges }
+    }
+
+    /// Get the file with the ID `file_id`, if it is in the graph.
+    pub fn file(&self, file_id: i32) -> Option<&ProjectFile> {
+        self.files.get(&file_id)
+    }
+
+    /// Iterates over every file in the graph, in order by ID.
+    pub fn files(&self) -> impl Iterator<Item = &ProjectFile> {
+        self.files.values()
+    }
+
+    /// Get every edge in the graph, in order by the ID of the file that
+    /// makes the reference.
+    pub fn edges(&self) -> &[FileEdge] {
+        &self.edges
+    }
+
+    /// Get the server pack of the file with the ID `file_id`. This is the file
+    /// referred to by its `server_pack_file_id`, or failing that, a server
+    /// pack whose `parent_project_file_id` refers to it.
+    pub fn server_pack_of(&self, file_id: i32) -> Option<&ProjectFile> {
+        self.edges
+            .iter()
+            .find(|edge| edge.from == file_id && edge.relation == FileRelation::ServerPack)
+            .and_then(|edge| self.file(edge.to))
+            .or_else(|| {
+                self.edges
+                    .iter()
+                    .filter(|edge| edge.to == file_id && edge.relation == FileRelation::Parent)
+                    .filter_map(|edge| self.file(edge.from))
+                    .find(|file| file.is_server_pack)
+            })
+    }
+
+    /// Get the file that the file with the ID `file_id` was made for, if it is
+    /// a server pack.
+    pub fn parent_of(&self, file_id: i32) -> Option<&ProjectFile> {
+        self.edges
+            .iter()
+            .find(|edge| edge.from == file_id && edge.relation == FileRelation::Parent)
+            .and_then(|edge| self.file(edge.to))
+    }
+
+    /// Get every file that is an alternate of the file with the ID `file_id`,
+    /// whichever of the two makes the reference, in order by ID.
+    pub fn alternates_of(&self, file_id: i32) -> Vec<&ProjectFile> {
+        let mut alternates = self
+            .edges
+            .iter()
+            .filter(|edge| edge.relation == FileRelation::Alternate)
+            .filter_map(|edge| match (edge.from, edge.to) {
+                (from, to) if from == file_id => Some(to),
+                (from, to) if to == file_id => Some(from),
+                _ => None,
+            })
+            .filter(|id| *id != file_id)
+            .filter_map(|id| self.file(id))
+            .collect::<Vec<_>>();
+
+        alternates.sort_by_key(|file| file.id);
+        alternates.dedup_by_key(|file| file.id);
+        alternates
+    }
+}
+
+impl Client {
+    /// Requests every file of a project, and builds the [`FileGraph`] of the
+    /// relationships between them. A project without any files has an empty
+    /// graph.
+    pub async fn file_relationship_graph(&self, project_id: i32) -> Result<FileGraph, Error> {
+        let delegate = ProjectFilesDelegate::new(
+            self.inner(),
+            self.base(),
+            project_id,
+            ProjectFilesParams::default(),
+        );
+        let pages = pages(delegate).try_collect::<Vec<_>>().await?;
+
+        Ok(FileGraph::new(pages.into_iter().flatten()))
+    }
+}
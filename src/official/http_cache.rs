@@ -0,0 +1,300 @@
+//! Contains the [`ResponseCache`] trait, used by
+//! [`ClientOptions::response_cache`] to revalidate responses with their `ETag`,
+//! and the `DiskResponseCache` implementation of it (with the `disk-cache`
+//! feature).
+//!
+//! [`ClientOptions::response_cache`]: crate::official::client::ClientOptions::response_cache
+
+use std::sync::Arc;
+
+use isahc::http::header::{ETAG, IF_NONE_MATCH};
+use isahc::http::{HeaderValue, Method, Request, Response, StatusCode};
+use isahc::interceptor::{Context, Interceptor, InterceptorFuture};
+use isahc::AsyncBody;
+
+#[cfg(feature = "disk-cache")]
+pub use disk::DiskResponseCache;
+
+/// A response body that was stored by a [`ResponseCache`], along with the
+/// `ETag` header that the API sent with it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// The value of the `ETag` header, including any quotes.
+    pub etag: String,
+    /// The body of the response, as it was received.
+    pub body: Vec<u8>,
+}
+
+/// Storage for the bodies of responses to `GET` requests, keyed by the full
+/// URI of the request.
+///
+/// When a client is constructed with a cache, every `GET` request for a URI
+/// that has a cached response is sent with an `If-None-Match` header. If the
+/// API responds with `304 Not Modified`, the cached body is used in place of
+/// the empty one. Any other successful response that has an `ETag` replaces
+/// the cached one.
+///
+/// The methods are called from within the request futures, and should not
+/// block for long. Errors should be swallowed, a cache that fails to read is
+/// the same as a cache that is empty.
+pub trait ResponseCache: std::fmt::Debug + Send + Sync {
+    /// Get the stored response for `key`, if there is one.
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Store `response` for `key`, replacing any previous response.
+    fn put(&self, key: &str, response: CachedResponse);
+}
+
+/// Revalidates `GET` requests against a [`ResponseCache`].
+#[derive(Debug)]
+pub(crate) struct EtagInterceptor {
+    cache: Arc<dyn ResponseCache>,
+}
+
+impl EtagInterceptor {
+    pub(crate) fn new(cache: Arc<dyn ResponseCache>) -> Self {
+        Self { cache }
+    }
+}
+
+impl Interceptor for EtagInterceptor {
+    type Err = isahc::Error;
+
+    fn intercept<'a>(
+        &'a self,
+        mut request: Request<AsyncBody>,
+        ctx: Context<'a>,
+    ) -> InterceptorFuture<'a, Self::Err> {
+        Box::pin(async move {
+            use futures_lite::io::AsyncReadExt;
+
+            if request.method() != Method::GET {
+                return ctx.send(request).await;
+            }
+
+            let key = request.uri().to_string();
+            let cached = self.cache.get(&key);
+
+            if let Some(cached) = &cached {
+                if let Ok(etag) = HeaderValue::from_str(&cached.etag) {
+                    request.headers_mut().insert(IF_NONE_MATCH, etag);
+                }
+            }
+
+            let (mut parts, mut body) = ctx.send(request).await?.into_parts();
+
+            if parts.status == StatusCode::NOT_MODIFIED {
+                if let Some(cached) = cached {
+                    parts.status = StatusCode::OK;
+                    return Ok(Response::from_parts(parts, AsyncBody::from(cached.body)));
+                }
+            }
+
+            let etag = parts
+                .headers
+                .get(ETAG)
+                .and_then(|etag| etag.to_str().ok())
+                .map(str::to_owned);
+
+            match etag {
+                Some(etag) if parts.status == StatusCode::OK => {
+                    let mut bytes = Vec::new();
+
+                    body.read_to_end(&mut bytes).await?;
+                    self.cache.put(
+                        &key,
+                        CachedResponse {
+                            etag,
+                            body: bytes.clone(),
+                        },
+                    );
+
+                    Ok(Response::from_parts(parts, AsyncBody::from(bytes)))
+                }
+                _ => Ok(Response::from_parts(parts, body)),
+            }
+        })
+    }
+}
+
+#[cfg(feature = "disk-cache")]
+mod disk {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use flate2::read::GzDecoder;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use super::{CachedResponse, ResponseCache};
+
+    /// A [`ResponseCache`] that keeps each response in a gzip-compressed file
+    /// in a directory, so that it survives between runs of a program.
+    ///
+    /// When the total size of the files in the directory exceeds the maximum,
+    /// the files that were written least recently are removed until it no
+    /// longer does. Files are read and written synchronously.
+    #[cfg_attr(docsrs, doc(cfg(feature = "disk-cache")))]
+    #[derive(Debug)]
+    pub struct DiskResponseCache {
+        dir: PathBuf,
+        max_size: u64,
+        sequence: AtomicU64,
+    }
+
+    impl DiskResponseCache {
+        /// Constructs a cache that keeps files in `dir`, which is created if it
+        /// does not exist, using at most `max_size` bytes.
+        pub fn new<P>(dir: P, max_size: u64) -> Self
+        where
+            P: Into<PathBuf>,
+        {
+            Self {
+                dir: dir.into(),
+                max_size,
+                sequence: AtomicU64::new(0),
+            }
+        }
+
+        /// Constructs a cache in [`Self::default_dir`], using at most
+        /// `max_size` bytes. Returns `None` if there is no default directory.
+        pub fn in_default_dir(max_size: u64) -> Option<Self> {
+            Self::default_dir().map(|dir| Self::new(dir, max_size))
+        }
+
+        /// Get the conventional directory for this cache, which is
+        /// `curseforge` under `$XDG_CACHE_HOME` if it is set, then under
+        /// `$HOME/.cache`, and then under `%LOCALAPPDATA%` on Windows.
+        pub fn default_dir() -> Option<PathBuf> {
+            let absolute = |var: &str| {
+                std::env::var_os(var)
+                    .map(PathBuf::from)
+                    .filter(|path| path.is_absolute())
+            };
+
+            absolute("XDG_CACHE_HOME")
+                .or_else(|| absolute("HOME").map(|home| home.join(".cache")))
+                .or_else(|| absolute("LOCALAPPDATA"))
+                .map(|dir| dir.join("curseforge"))
+        }
+
+        /// Get the directory that files are kept in.
+        pub fn dir(&self) -> &Path {
+            &self.dir
+        }
+
+        /// Get the maximum total size of the files, in bytes.
+        pub fn max_size(&self) -> u64 {
+            self.max_size
+        }
+
+        /// Remove every file from the cache.
+        pub fn clear(&self) -> std::io::Result<()> {
+            for path in self.entries()?.into_iter().map(|(path, _, _)| path) {
+                std::fs::remove_file(path)?;
+            }
+
+            Ok(())
+        }
+
+        /// The file name for a key is the 64-bit FNV-1a hash of it, which is
+        /// stable between builds, unlike the hasher of the standard library.
+        fn path(&self, key: &str) -> PathBuf {
+            let hash = key.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+            });
+
+            self.dir.join(format!("{:016x}.gz", hash))
+        }
+
+        fn entries(&self) -> std::io::Result<Vec<(PathBuf, u64, std::time::SystemTime)>> {
+            let mut entries = Vec::new();
+
+            for entry in std::fs::read_dir(&self.dir)? {
+                let entry = entry?;
+                let path = entry.path();
+
+                if path.extension().is_some_and(|extension| extension == "gz") {
+                    let metadata = entry.metadata()?;
+                    entries.push((path, metadata.len(), metadata.modified()?));
+                }
+            }
+
+            Ok(entries)
+        }
+
+        fn read(&self, key: &str) -> std::io::Result<Option<CachedResponse>> {
+            let file = std::fs::File::open(self.path(key))?;
+            let mut reader = BufReader::new(GzDecoder::new(file));
+            let mut stored_key = String::new();
+            let mut etag = String::new();
+            let mut body = Vec::new();
+
+            reader.read_line(&mut stored_key)?;
+            reader.read_line(&mut etag)?;
+            reader.read_to_end(&mut body)?;
+
+            if stored_key.trim_end_matches('\n') != key {
+                return Ok(None);
+            }
+
+            Ok(Some(CachedResponse {
+                etag: etag.trim_end_matches('\n').to_owned(),
+                body,
+            }))
+        }
+
+        fn write(&self, key: &str, response: &CachedResponse) -> std::io::Result<()> {
+            std::fs::create_dir_all(&self.dir)?;
+
+            // Write to a temporary file first, so that a reader never sees a
+            // partially written file.
+            let sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+            let path = self.path(key);
+            let temporary = path.with_extension(format!("{}.{}.tmp", std::process::id(), sequence));
+            let mut encoder =
+                GzEncoder::new(std::fs::File::create(&temporary)?, Compression::default());
+
+            writeln!(encoder, "{}", key)?;
+            writeln!(encoder, "{}", response.etag)?;
+            encoder.write_all(&response.body)?;
+            encoder.finish()?;
+
+            std::fs::rename(temporary, path)
+        }
+
+        fn evict(&self) -> std::io::Result<()> {
+            let mut entries = self.entries()?;
+            let mut total = entries.iter().map(|(_, size, _)| size).sum::<u64>();
+
+            entries.sort_by_key(|(_, _, modified)| *modified);
+
+            for (path, size, _) in entries {
+                if total <= self.max_size {
+                    break;
+                }
+
+                std::fs::remove_file(path)?;
+                total -= size;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl ResponseCache for DiskResponseCache {
+        fn get(&self, key: &str) -> Option<CachedResponse> {
+            self.read(key).ok().flatten()
+        }
+
+        fn put(&self, key: &str, response: CachedResponse) {
+            // Keys and tags are stored on their own lines.
+            if key.contains('\n') || response.etag.contains('\n') {
+                return;
+            }
+
+            let _ = self.write(key, &response).and_then(|_| self.evict());
+        }
+    }
+}
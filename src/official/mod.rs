@@ -2,24 +2,65 @@
 //!
 //! It is recommended to use the [`prelude`].
 
+#[doc(hidden)]
+pub mod batch;
+#[doc(hidden)]
+pub mod cache;
+mod catalog;
 #[doc(hidden)]
 pub mod client;
+mod download;
+mod dump;
 pub mod endpoints;
+#[doc(hidden)]
+pub mod graph;
+#[doc(hidden)]
+pub mod http_cache;
+#[doc(hidden)]
+pub mod modpack;
+#[doc(hidden)]
+pub mod rate_limit;
 pub mod request;
+#[doc(hidden)]
+pub mod retry;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 pub mod types;
+mod unknown_fields;
+#[doc(hidden)]
+pub mod updates;
+mod utf8;
 
 pub use crate::Error;
+pub use cache::GameMetadataCache;
 pub use client::Client;
+pub use updates::{ModUpdateStatus, UpdateCandidate};
 
 /// All members defined within this crate are re-exported flatly at this path
 /// for convenience.
 pub mod prelude {
-    pub use super::client::{Client, ClientOptions};
+    pub use super::batch::{ChunkedResults, Concurrency};
+    pub use super::cache::GameMetadataCache;
+    pub use super::client::{
+        Client, ClientBuilder, ClientOptions, DefaultParams, DefaultParamsClient, ProxyConfig,
+        ProxyCredentials, TimedClient,
+    };
     pub use super::endpoints as e;
     #[doc(inline)]
     pub use super::endpoints::DEFAULT_API_BASE as CF_DEFAULT_API_BASE;
+    pub use super::graph::{FileEdge, FileGraph, FileRelation};
+    #[cfg(feature = "disk-cache")]
+    pub use super::http_cache::DiskResponseCache;
+    pub use super::http_cache::{CachedResponse, ResponseCache};
+    pub use super::modpack::{
+        DownloadTargets, ManifestFile, ManifestMinecraft, ManifestModLoader, ModpackManifest,
+    };
+    pub use super::rate_limit::{RateLimitInfo, ThrottleConfig};
     #[doc(inline)]
     pub use super::request::*;
+    pub use super::retry::RetryConfig;
     #[doc(inline)]
     pub use super::types::*;
+    pub use super::updates::{ModUpdateStatus, UpdateCandidate};
 }
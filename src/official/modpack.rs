@@ -0,0 +1,316 @@
+//! Contains [`ModpackManifest`], the `manifest.json` file at the root of every
+//! modpack file, [`Client`] methods to measure the files that it refers to, to
+//! plan where they are downloaded, and to verify them once they have been, and
+//! with the `modpack` feature, [`Client`] methods to read it from a modpack and
+//! look up those files and their projects.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::official::client::Client;
+use crate::official::types::{total_download_size, HashMismatch, ProjectFile};
+use crate::Error;
+
+/// The `manifest.json` file at the root of the zip archive of a modpack file,
+/// which lists the files that make up the modpack.
+///
+/// This is not returned by the API, it is read from a downloaded file. Unknown
+/// fields are always ignored.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ModpackManifest {
+    pub minecraft: ManifestMinecraft,
+    /// This is `minecraftModpack` for every known modpack.
+    pub manifest_type: String,
+    pub manifest_version: u32,
+    pub name: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    pub files: Vec<ManifestFile>,
+    /// The directory in the archive whose contents are copied over the game
+    /// directory when the modpack is installed.
+    #[serde(default)]
+    pub overrides: Option<String>,
+}
+
+/// The game version and mod loaders that a [`ModpackManifest`] is made for.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestMinecraft {
+    pub version: String,
+    #[serde(default)]
+    pub mod_loaders: Vec<ManifestModLoader>,
+}
+
+/// A mod loader that a [`ModpackManifest`] is made for.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestModLoader {
+    /// The name and version of the loader, such as `forge-47.2.0`.
+    pub id: String,
+    #[serde(default)]
+    pub primary: bool,
+}
+
+/// A project file that is part of a [`ModpackManifest`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ManifestFile {
+    #[serde(rename = "projectID")]
+    pub project_id: i32,
+    #[serde(rename = "fileID")]
+    pub file_id: i32,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+/// Where the files of a [`ModpackManifest`] are downloaded to, see
+/// [`Client::manifest_download_targets`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DownloadTargets {
+    /// The project ID, file ID, and destination path of each file that the API
+    /// returned, in the order of the manifest.
+    pub targets: Vec<(i32, i32, PathBuf)>,
+    /// The project ID and file ID of each file that the API did not return,
+    /// in the order of the manifest.
+    pub unresolved: Vec<(i32, i32)>,
+}
+
+impl Client {
+    /// Requests every file listed in `manifest` with a single batched request,
+    /// keyed by file ID. Files that the API did not return, such as those
+    /// that have been deleted, are missing from the map.
+    async fn manifest_files(
+        &self,
+        manifest: &ModpackManifest,
+    ) -> Result<HashMap<i32, ProjectFile>, Error> {
+        if manifest.files.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        Ok(self
+            .project_files_by_ids(manifest.files.iter().map(|file| file.file_id))
+            .await?
+            .into_iter()
+            .map(|file| (file.id, file))
+            .collect())
+    }
+
+    /// Requests every file listed in `manifest` with a single batched request,
+    /// and sums their sizes with [`total_download_size`]. This includes the
+    /// files that are not required.
+    ///
+    /// Files that the API did not return, such as those that have been
+    /// deleted, add nothing to the total, as do files without a known length.
+    pub async fn manifest_download_size(&self, manifest: &ModpackManifest) -> Result<i64, Error> {
+        let files = self.manifest_files(manifest).await?;
+
+        Ok(total_download_size(
+            &files.into_values().collect::<Vec<_>>(),
+        ))
+    }
+
+    /// Requests every file listed in `manifest` with a single batched request,
+    /// and returns the project ID, file ID, and destination path of each. The
+    /// path is in `dest_dir`, named with [`ProjectFile::safe_filename`], which
+    /// is where [`Client::download_file_to`] would write it.
+    ///
+    /// The targets are in the order of the manifest, and include the files
+    /// that are not required. Files that the API did not return, such as those
+    /// that have been deleted, have no target, and are listed in
+    /// [`DownloadTargets::unresolved`] instead.
+    ///
+    /// If two different files would be written to the same path, ignoring
+    /// case, [`Error::TargetCollision`] is returned, since downloading both
+    /// would leave only one of them behind.
+    ///
+    /// [`ProjectFile::safe_filename`]: crate::official::types::ProjectFile::safe_filename
+    pub async fn manifest_download_targets(
+        &self,
+        manifest: &ModpackManifest,
+        dest_dir: &Path,
+    ) -> Result<DownloadTargets, Error> {
+        let files = self.manifest_files(manifest).await?;
+        let mut names = HashMap::new();
+        let mut targets = DownloadTargets::default();
+
+        for entry in &manifest.files {
+            let file = match files.get(&entry.file_id) {
+                Some(file) => file,
+                None => {
+                    targets.unresolved.push((entry.project_id, entry.file_id));
+                    continue;
+                }
+            };
+            let name = file.safe_filename();
+            let path = dest_dir.join(&name);
+
+            match names.insert(name.to_lowercase(), file.id) {
+                Some(first) if first != file.id => {
+                    return Err(Error::TargetCollision {
+                        first,
+                        second: file.id,
+                        path,
+                    })
+                }
+                _ => targets
+                    .targets
+                    .push((entry.project_id, entry.file_id, path)),
+            }
+        }
+
+        Ok(targets)
+    }
+
+    /// Requests every file listed in `manifest` with a single batched request,
+    /// and checks each that has been downloaded to `dest_dir` with
+    /// [`ProjectFile::verify_reader`] against the hashes that the API
+    /// reported, where [`Self::manifest_download_targets`] places it. Each
+    /// file is read in chunks on a thread for blocking work, so that the
+    /// executor is not stalled.
+    ///
+    /// Every file is checked, and is paired with its project ID and file ID,
+    /// in the order of the manifest. A file that is missing from `dest_dir` is
+    /// reported as [`HashMismatch::Io`], and a file that the API did not
+    /// return as [`HashMismatch::Unresolved`].
+    ///
+    /// [`ProjectFile::verify_reader`]: crate::official::types::ProjectFile::verify_reader
+    pub async fn verify_manifest_files(
+        &self,
+        manifest: &ModpackManifest,
+        dest_dir: &Path,
+    ) -> Result<Vec<(i32, i32, Result<(), HashMismatch>)>, Error> {
+        let files = self.manifest_files(manifest).await?;
+        let mut results = Vec::with_capacity(manifest.files.len());
+
+        for entry in &manifest.files {
+            let result = match files.get(&entry.file_id).cloned() {
+                Some(file) => {
+                    let path = dest_dir.join(file.safe_filename());
+
+                    blocking::unblock(move || {
+                        std::fs::File::open(path)
+                            .map_err(HashMismatch::from)
+                            .and_then(|handle| file.verify_reader(handle))
+                    })
+                    .await
+                }
+                None => Err(HashMismatch::Unresolved),
+            };
+
+            results.push((entry.project_id, entry.file_id, result));
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(feature = "modpack")]
+mod contents {
+    use std::collections::HashMap;
+    use std::io::{Read, Seek};
+
+    use super::ModpackManifest;
+    use crate::official::client::Client;
+    use crate::official::types::{Project, ProjectFile};
+    use crate::Error;
+
+    impl ModpackManifest {
+        /// Reads the `manifest.json` file from the zip archive of a modpack.
+        /// The `uri` that the archive was downloaded from is only used for
+        /// errors.
+        #[cfg_attr(docsrs, doc(cfg(feature = "modpack")))]
+        pub fn from_zip<R>(uri: url::Url, archive: R) -> Result<Self, Error>
+        where
+            R: Read + Seek,
+        {
+            let mut archive = zip::ZipArchive::new(archive)?;
+            let mut bytes = Vec::new();
+
+            archive.by_name("manifest.json")?.read_to_end(&mut bytes)?;
+
+            let deser = &mut serde_json::Deserializer::from_slice(&bytes);
+
+            serde_path_to_error::deserialize(deser).map_err(|error| Error::Deserialize {
+                uri,
+                error,
+                bytes: Box::new(bytes),
+            })
+        }
+    }
+
+    impl Client {
+        /// Downloads a modpack file, and reads its [`ModpackManifest`]. The
+        /// whole file is kept in memory while it is read.
+        #[cfg_attr(docsrs, doc(cfg(feature = "modpack")))]
+        pub async fn modpack_manifest(
+            &self,
+            project_id: i32,
+            file_id: i32,
+        ) -> Result<ModpackManifest, Error> {
+            use crate::official::endpoints as e;
+
+            let file = self.project_file(project_id, file_id).await?;
+            let uri = match file.resolved_download_url()? {
+                Some(uri) => uri,
+                None => e::construct_download_url(file.id, &file.file_name),
+            };
+            let mut bytes = Vec::new();
+
+            self.download_file(&file, &mut bytes).await?;
+
+            ModpackManifest::from_zip(uri, std::io::Cursor::new(bytes))
+        }
+
+        /// Downloads a modpack file with [`Self::modpack_manifest`], and
+        /// requests every file listed in its manifest along with the project
+        /// that the file belongs to. The files and the projects are each
+        /// requested with a single batched request.
+        ///
+        /// The pairs are returned in the same order as the manifest lists
+        /// them. Files that the API did not return, such as those that have
+        /// been deleted, are left out, compare with the manifest to find them.
+        #[cfg_attr(docsrs, doc(cfg(feature = "modpack")))]
+        pub async fn modpack_contents(
+            &self,
+            project_id: i32,
+            file_id: i32,
+        ) -> Result<Vec<(ProjectFile, Project)>, Error> {
+            let manifest = self.modpack_manifest(project_id, file_id).await?;
+
+            if manifest.files.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let mut files = self.manifest_files(&manifest).await?;
+            let projects = self
+                .projects(manifest.files.iter().map(|file| file.project_id))
+                .await?
+                .into_iter()
+                .map(|project| (project.id, project))
+                .collect::<HashMap<_, _>>();
+
+            Ok(manifest
+                .files
+                .iter()
+                .filter_map(|entry| {
+                    let file = files.remove(&entry.file_id)?;
+                    let project = projects.get(&entry.project_id)?.clone();
+
+                    Some((file, project))
+                })
+                .collect())
+        }
+    }
+}
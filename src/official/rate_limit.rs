@@ -0,0 +1,197 @@
+//! Contains [`RateLimitInfo`], and the interceptor that records it for
+//! [`Client::last_rate_limit`], as well as [`ThrottleConfig`], and the
+//! interceptor that delays requests for [`ClientOptions::throttle`].
+//!
+//! [`Client::last_rate_limit`]: crate::official::client::Client::last_rate_limit
+//! [`ClientOptions::throttle`]: crate::official::client::ClientOptions::throttle
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, TimeZone, Utc};
+use isahc::http::{HeaderMap, Request};
+use isahc::interceptor::{Context, Interceptor, InterceptorFuture};
+use isahc::AsyncBody;
+
+/// The state of the rate limit, as reported by the `x-ratelimit-*` headers of
+/// a response. Not every API host sends these headers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// The number of requests allowed in the current window, from
+    /// `x-ratelimit-limit`.
+    pub limit: u64,
+    /// The number of requests left in the current window, from
+    /// `x-ratelimit-remaining`.
+    pub remaining: u64,
+    /// When the current window ends, from `x-ratelimit-reset` if it was sent.
+    /// The header may either be a number of seconds from when the response
+    /// was received, or a Unix timestamp; both are accepted.
+    pub reset: Option<DateTime<Utc>>,
+}
+
+impl RateLimitInfo {
+    /// Parses the rate limit from the headers of a response, returning `None`
+    /// unless both `x-ratelimit-limit` and `x-ratelimit-remaining` are present
+    /// and numeric.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        // Values smaller than this are too early to be a timestamp, which
+        // would be more than 30 years in the past.
+        const TIMESTAMP_THRESHOLD: i64 = 1_000_000_000;
+
+        let number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+
+        let reset = number("x-ratelimit-reset")
+            .and_then(|reset| i64::try_from(reset).ok())
+            .and_then(|reset| {
+                if reset >= TIMESTAMP_THRESHOLD {
+                    Utc.timestamp_opt(reset, 0).single()
+                } else {
+                    Some(Utc::now() + chrono::Duration::seconds(reset))
+                }
+            });
+
+        Some(Self {
+            limit: number("x-ratelimit-limit")?,
+            remaining: number("x-ratelimit-remaining")?,
+            reset,
+        })
+    }
+}
+
+/// Records the [`RateLimitInfo`] of every response that carries it.
+#[derive(Debug)]
+pub(crate) struct RateLimitRecorder {
+    last: Arc<Mutex<Option<RateLimitInfo>>>,
+}
+
+impl RateLimitRecorder {
+    pub(crate) fn new(last: Arc<Mutex<Option<RateLimitInfo>>>) -> Self {
+        Self { last }
+    }
+}
+
+impl Interceptor for RateLimitRecorder {
+    type Err = isahc::Error;
+
+    fn intercept<'a>(
+        &'a self,
+        request: Request<AsyncBody>,
+        ctx: Context<'a>,
+    ) -> InterceptorFuture<'a, Self::Err> {
+        Box::pin(async move {
+            let response = ctx.send(request).await?;
+
+            if let Some(info) = RateLimitInfo::from_headers(response.headers()) {
+                *self.last.lock().unwrap() = Some(info);
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+/// Settings for limiting the rate that a client sends requests at, see
+/// [`ClientOptions::throttle`].
+///
+/// Requests are drawn from a bucket that holds at most `burst` of them, and
+/// regains `requests_per_second` of them each second. When the bucket is
+/// empty, a request waits until it has been refilled before it is sent, so
+/// that the client stays under the API's limits instead of being rejected by
+/// them. This applies to every request sent by the client (including clones of
+/// it), and to every attempt of a retried request.
+///
+/// [`ClientOptions::throttle`]: crate::official::client::ClientOptions::throttle
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThrottleConfig {
+    /// The number of requests that may be sent each second, on average.
+    pub requests_per_second: f64,
+    /// The number of requests that may be sent at once, after the client has
+    /// been idle.
+    pub burst: u32,
+}
+
+impl ThrottleConfig {
+    /// Instantiate this structure with a rate, and a burst of a single request.
+    pub fn per_second(requests_per_second: f64) -> Self {
+        Self {
+            requests_per_second,
+            burst: 1,
+        }
+    }
+}
+
+/// The state of the token bucket that requests are drawn from. The tokens may
+/// be negative, when requests are waiting for ones that have been reserved.
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
+}
+
+/// Delays requests according to a [`ThrottleConfig`].
+#[derive(Debug)]
+pub(crate) struct Throttle {
+    config: ThrottleConfig,
+    bucket: Mutex<Bucket>,
+}
+
+impl Throttle {
+    pub(crate) fn new(config: ThrottleConfig) -> Self {
+        Self {
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(config.burst.max(1)),
+                refilled: Instant::now(),
+            }),
+            config,
+        }
+    }
+
+    /// Takes a request from the bucket, returning how long to wait before it
+    /// may be sent.
+    fn reserve(&self) -> Duration {
+        let rate = self.config.requests_per_second;
+
+        if !(rate > 0.0 && rate.is_finite()) {
+            return Duration::ZERO;
+        }
+
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.refilled).as_secs_f64() * rate;
+
+        bucket.tokens = (bucket.tokens + refill).min(f64::from(self.config.burst.max(1)));
+        bucket.refilled = now;
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / rate)
+        }
+    }
+}
+
+impl Interceptor for Throttle {
+    type Err = isahc::Error;
+
+    fn intercept<'a>(
+        &'a self,
+        request: Request<AsyncBody>,
+        ctx: Context<'a>,
+    ) -> InterceptorFuture<'a, Self::Err> {
+        Box::pin(async move {
+            let wait = self.reserve();
+
+            if !wait.is_zero() {
+                futures_timer::Delay::new(wait).await;
+            }
+
+            ctx.send(request).await
+        })
+    }
+}
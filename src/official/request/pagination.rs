@@ -1,10 +1,33 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
 use async_trait::async_trait;
 use awaur::paginator::{PaginatedStream, PaginationDelegate};
+use serde::{Deserialize, Serialize};
 
 use super::params::{GamesParams, ProjectFilesParams, ProjectSearchParams};
 use crate::official::endpoints as e;
 use crate::official::endpoints::API_PAGINATION_RESULTS_LIMIT;
-use crate::official::types::{Game, Pagination, Project, ProjectFile};
+use crate::official::retry::RetryConfig;
+use crate::official::types::{Category, Game, Project, ProjectFile, ProjectSummary};
+
+/// The position of a paginated scan, which can be stored between runs of a
+/// long-running job, and given back to resume from exactly the same place.
+/// See [`CheckpointedSearchStream::checkpoint`] and
+/// [`e::search_projects_iter_from_checkpoint`].
+///
+/// A new scan is started from the default checkpoint.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ScanCheckpoint {
+    /// The index of the next item to request.
+    pub index: usize,
+    /// The total number of items that can be requested, capped at
+    /// [`API_PAGINATION_RESULTS_LIMIT`], as of the last page received. This is
+    /// `None` if no page had been received yet.
+    pub total: Option<usize>,
+}
 
 macro_rules! pagination_delegate {
     (
@@ -16,12 +39,17 @@ macro_rules! pagination_delegate {
         }
     ) => {
         /// See the documentation for [`PaginationDelegate`].
+        ///
+        /// The current position can be read with [`PaginationDelegate::offset`],
+        /// and a clone of the delegate taken at any point can be converted into a
+        /// new stream that resumes from that position.
+        #[derive(Clone, Debug)]
         pub struct $name<'cu> {
             client: &'cu isahc::HttpClient,
             base: &'cu url::Url,
             $($($var: $var_type,)*)?
             params: $params,
-            pagination: Option<Pagination>,
+            total: Option<usize>,
         }
 
         impl<'cu> $name<'cu> {
@@ -40,7 +68,39 @@ macro_rules! pagination_delegate {
                     base,
                     $($($var,)*)?
                     params,
-                    pagination: None,
+                    total: None,
+                }
+            }
+
+            /// Constructs a delegate that resumes from `checkpoint`, ignoring
+            /// the `index` in `params`. The total of the checkpoint is kept
+            /// until the next page is received.
+            pub fn from_checkpoint(
+                client: &'cu isahc::HttpClient,
+                base: &'cu url::Url,
+                $($($var: $var_type,)*)?
+                mut params: $params,
+                checkpoint: ScanCheckpoint,
+            ) -> Self {
+                params.index = Some(checkpoint.index as i32);
+
+                Self {
+                    client,
+                    base,
+                    $($($var,)*)?
+                    params,
+                    total: checkpoint.total,
+                }
+            }
+
+            /// Get the position of the delegate, to be stored and later given
+            /// to [`Self::from_checkpoint`]. This is the position after the
+            /// last page that the delegate has received, so it does not
+            /// account for items of that page that a stream has yet to yield.
+            pub fn checkpoint(&self) -> ScanCheckpoint {
+                ScanCheckpoint {
+                    index: self.offset(),
+                    total: self.total,
                 }
             }
         }
@@ -59,7 +119,10 @@ macro_rules! pagination_delegate {
                     )
                     .await?
                     .into_value();
-                self.pagination = Some(result.pagination);
+                self.total = Some(usize::min(
+                    API_PAGINATION_RESULTS_LIMIT,
+                    result.pagination.total_count as usize,
+                ));
                 Ok(result.data)
             }
 
@@ -72,12 +135,7 @@ macro_rules! pagination_delegate {
             }
 
             fn total_items(&self) -> Option<usize> {
-                self.pagination.as_ref().map(|pagination| {
-                    usize::min(
-                        API_PAGINATION_RESULTS_LIMIT,
-                        pagination.total_count as usize,
-                    )
-                })
+                self.total
             }
         }
     };
@@ -108,9 +166,246 @@ pagination_delegate! {
     }
 }
 
+/// Wraps another [`PaginationDelegate`], and requests a page again when it
+/// fails with an error that [`is_transient`], rather than ending the stream.
+///
+/// A failed page is requested again from the same offset, at most
+/// `max_retries` times, waiting for [`RetryConfig::backoff`] before each
+/// retry. If every attempt fails, the last error is yielded, and the stream
+/// ends as usual. The attempts are counted separately for each page, so the
+/// budget of the [`RetryConfig`] is not used.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use curseforge::official::prelude::*;
+/// # let client = Client::new(e::DEFAULT_API_BASE, None, None).unwrap();
+/// let params = ProjectSearchParams::game(432);
+/// let projects: RetryingStream<'_, _> =
+///     client.search_projects_iter_retrying(params, 3, Duration::from_secs(1));
+/// ```
+///
+/// [`is_transient`]: crate::Error::is_transient
+#[derive(Clone, Debug)]
+pub struct RetryingDelegate<D> {
+    delegate: D,
+    config: RetryConfig,
+}
+
+impl<D> RetryingDelegate<D> {
+    /// Wraps `delegate`, retrying each page at most `max_retries` times, with
+    /// delays that start at `base_delay`. The other settings keep the default
+    /// of [`RetryConfig`].
+    pub fn new(delegate: D, max_retries: u32, base_delay: Duration) -> Self {
+        Self::with_config(
+            delegate,
+            RetryConfig {
+                max_retries,
+                base_delay,
+                ..RetryConfig::default()
+            },
+        )
+    }
+
+    /// Wraps `delegate`, retrying each page according to `config`.
+    pub fn with_config(delegate: D, config: RetryConfig) -> Self {
+        Self { delegate, config }
+    }
+
+    /// Take out the wrapped delegate, which has the same position.
+    pub fn into_inner(self) -> D {
+        self.delegate
+    }
+}
+
+#[async_trait]
+impl<D> PaginationDelegate for RetryingDelegate<D>
+where
+    D: PaginationDelegate<Error = crate::Error> + Send,
+    D::Item: Send,
+{
+    type Item = D::Item;
+    type Error = crate::Error;
+
+    async fn next_page(&mut self) -> Result<Vec<Self::Item>, Self::Error> {
+        let mut attempt = 0;
+
+        loop {
+            match self.delegate.next_page().await {
+                Err(error) if error.is_transient() && attempt < self.config.max_retries => {
+                    futures_timer::Delay::new(self.config.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn offset(&self) -> usize {
+        self.delegate.offset()
+    }
+
+    fn set_offset(&mut self, value: usize) {
+        self.delegate.set_offset(value);
+    }
+
+    fn total_items(&self) -> Option<usize> {
+        self.delegate.total_items()
+    }
+}
+
+/// Yields each page from `delegate`. Unlike [`PaginatedStream`], this ends
+/// quietly when the first page is empty, and yields whole pages.
+pub(crate) fn pages<'f, D>(
+    delegate: D,
+) -> impl futures_lite::Stream<Item = Result<Vec<D::Item>, D::Error>> + 'f
+where
+    D: PaginationDelegate + 'f,
+{
+    use futures_util::TryStreamExt;
+
+    pages_with_total(delegate).map_ok(|(page, _)| page)
+}
+
+/// Like [`pages`], but yields each page together with the total of the
+/// delegate after that page was received.
+#[allow(clippy::type_complexity)]
+fn pages_with_total<'f, D>(
+    delegate: D,
+) -> impl futures_lite::Stream<Item = Result<(Vec<D::Item>, Option<usize>), D::Error>> + 'f
+where
+    D: PaginationDelegate + 'f,
+{
+    futures_util::stream::unfold(Some(delegate), |delegate| async move {
+        let mut delegate = delegate?;
+
+        match delegate.next_page().await {
+            Ok(page) if page.is_empty() => None,
+            Ok(page) => {
+                delegate.set_offset(delegate.offset() + page.len());
+
+                let total = delegate.total_items();
+                let done = total.is_some_and(|total| delegate.offset() >= total);
+
+                Some((Ok((page, total)), (!done).then_some(delegate)))
+            }
+            Err(error) => Some((Err(error), None)),
+        }
+    })
+}
+
+/// Yields the projects of a search that starts from a [`ScanCheckpoint`], see
+/// [`e::search_projects_iter_from_checkpoint`].
+///
+/// The position of the scan can be taken with [`Self::checkpoint`] between
+/// any two items. Unlike [`ProjectSearchStream`], the scan ends quietly if the
+/// API returns an empty page, such as when the total of a stored checkpoint
+/// has gone stale.
+pub struct CheckpointedSearchStream<'f> {
+    #[allow(clippy::type_complexity)]
+    pages: Pin<
+        Box<
+            dyn futures_lite::Stream<Item = Result<(Vec<Project>, Option<usize>), crate::Error>>
+                + 'f,
+        >,
+    >,
+    items: VecDeque<Project>,
+    checkpoint: ScanCheckpoint,
+}
+
+impl<'f> CheckpointedSearchStream<'f> {
+    pub(crate) fn new<'cu>(
+        client: &'cu isahc::HttpClient,
+        base: &'cu url::Url,
+        params: ProjectSearchParams,
+        checkpoint: ScanCheckpoint,
+    ) -> Self
+    where
+        'cu: 'f,
+    {
+        let done = checkpoint
+            .total
+            .is_some_and(|total| checkpoint.index >= total);
+        let pages: Pin<Box<dyn futures_lite::Stream<Item = _> + 'f>> = if done {
+            Box::pin(futures_lite::stream::empty())
+        } else {
+            Box::pin(pages_with_total(ProjectSearchDelegate::from_checkpoint(
+                client, base, params, checkpoint,
+            )))
+        };
+
+        Self {
+            pages,
+            items: VecDeque::new(),
+            checkpoint,
+        }
+    }
+
+    /// Get the position of the scan, to be stored and later given to
+    /// [`e::search_projects_iter_from_checkpoint`]. This is the index of the
+    /// next project that will be yielded, so projects of the current page
+    /// that have not been yielded yet are requested again when resuming.
+    pub fn checkpoint(&self) -> ScanCheckpoint {
+        self.checkpoint
+    }
+}
+
+impl futures_lite::Stream for CheckpointedSearchStream<'_> {
+    type Item = Result<Project, crate::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(project) = self.items.pop_front() {
+                self.checkpoint.index += 1;
+                return Poll::Ready(Some(Ok(project)));
+            }
+
+            match self.pages.as_mut().poll_next(ctx) {
+                Poll::Ready(Some(Ok((page, total)))) => {
+                    self.checkpoint.total = total;
+                    self.items = page.into();
+                }
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
 /// See the documentation for [`PaginatedStream`].
 pub type GamesStream<'cu, 'f> = PaginatedStream<'f, GamesDelegate<'cu>>;
 /// See the documentation for [`PaginatedStream`].
 pub type ProjectSearchStream<'cu, 'f> = PaginatedStream<'f, ProjectSearchDelegate<'cu>>;
-/// See the documentation for [`PaginatedStream`].
-pub type ProjectFilesStream<'cu, 'f> = PaginatedStream<'f, ProjectFilesDelegate<'cu>>;
+/// See the documentation for [`PaginatedStream`], which this wraps. When
+/// [`ProjectFilesParams::release_type`] is set, files of other release types
+/// are skipped after each page has been received.
+///
+/// The stream is boxed, so `'cu`, the lifetime of the client that it borrows,
+/// only has to outlive `'f`. It is kept so that this has the same parameters
+/// as the other stream types.
+pub type ProjectFilesStream<'cu, 'f> =
+    Pin<Box<dyn futures_lite::Stream<Item = Result<ProjectFile, crate::Error>> + 'f>>;
+/// See the documentation for [`PaginatedStream`]. Each [`Project`] is trimmed
+/// down to a [`ProjectSummary`] as soon as it is yielded.
+pub type ProjectSummaryStream<'cu, 'f> = futures_lite::stream::Map<
+    ProjectSearchStream<'cu, 'f>,
+    fn(Result<Project, crate::Error>) -> Result<ProjectSummary, crate::Error>,
+>;
+/// See the documentation for [`RetryingDelegate`].
+pub type RetryingStream<'f, D> = PaginatedStream<'f, RetryingDelegate<D>>;
+/// The categories endpoint is not paginated by the API, so this stream makes a
+/// single request when first polled, and then yields each [`Category`] from
+/// the response. It exists to provide the same interface as the other streams.
+pub type CategoriesStream<'f> =
+    Pin<Box<dyn futures_lite::Stream<Item = Result<Category, crate::Error>> + 'f>>;
+/// Yields every project of a game, see [`Client::all_projects_for_game`].
+///
+/// [`Client::all_projects_for_game`]: crate::official::client::Client::all_projects_for_game
+pub type AllProjectsStream<'f> =
+    Pin<Box<dyn futures_lite::Stream<Item = Result<Project, crate::Error>> + 'f>>;
+/// Yields each file of a project paired with its changelog. See
+/// [`Client::project_changelog_stream`].
+///
+/// [`Client::project_changelog_stream`]: crate::official::client::Client::project_changelog_stream
+pub type ProjectChangelogStream<'f> =
+    Pin<Box<dyn futures_lite::Stream<Item = Result<(ProjectFile, String), crate::Error>> + 'f>>;
@@ -1,12 +1,20 @@
-use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use serde_with::{DeserializeFromStr, SerializeDisplay};
 use strum::{Display, EnumString};
 
+use crate::official::types::files::FileReleaseType;
 use crate::official::types::projects::ModLoaderType;
+use crate::Error;
 
 /// <https://docs.curseforge.com/#get-games>
-#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+///
+/// Like the other parameter types, this can also be deserialized, so that a
+/// request can be described by a configuration file. The names of the fields
+/// are the same as the names of the parameters that the API accepts.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GamesParams {
     pub index: Option<i32>,
@@ -14,25 +22,39 @@ pub struct GamesParams {
 }
 
 /// <https://docs.curseforge.com/#get-categories>
-#[derive(Clone, Debug, PartialEq, Serialize)]
+///
+/// The fields `game_id`, `class_id`, and `classes_only` are filters applied by
+/// the API. The API does not support querying for the children of a category,
+/// so `parent_category_id` is never sent, and is instead applied to the
+/// response after it has been received.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CategoriesParams {
     pub game_id: i32,
     pub class_id: Option<i32>,
+    pub classes_only: Option<bool>,
+    #[serde(default, skip_serializing)]
+    pub parent_category_id: Option<i32>,
 }
 
 impl CategoriesParams {
-    /// Instantiate this structure with a `game_id` and no `class_id`.
+    /// Instantiate this structure with a `game_id` and no other filters.
     pub fn game(game_id: i32) -> Self {
         Self {
             game_id,
             class_id: None,
+            classes_only: None,
+            parent_category_id: None,
         }
     }
 }
 
 /// <https://docs.curseforge.com/#search-mods>
-#[derive(Clone, Debug, PartialEq, Serialize)]
+///
+/// Every search is limited to a single game, the API does not support
+/// searching the projects of all games at once. To search several games, make
+/// one search for each of them.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectSearchParams {
     pub game_id: i32,
@@ -45,12 +67,40 @@ pub struct ProjectSearchParams {
     #[serde(rename = "modLoaderType")]
     pub mod_loader: Option<ModLoaderType>,
     pub game_version_type_id: Option<i32>,
+    pub author_id: Option<i32>,
     pub slug: Option<String>,
     pub index: Option<i32>,
     pub page_size: Option<i32>,
+    /// Additional parameters that are sent verbatim, for filters that the API
+    /// supports but this structure does not have a field for yet. A parameter
+    /// with the same name as one of the fields above is ignored, the field
+    /// takes precedence. See [`Self::query_string`].
+    #[serde(default, skip_serializing)]
+    pub extra: BTreeMap<String, String>,
 }
 
 impl ProjectSearchParams {
+    const GAME_REQUIRED: &'static str =
+        "a search must be for a single game, the API does not support searching across games";
+
+    /// This always returns an error, because the API requires a game for every
+    /// search. It exists so that looking for a cross-game search leads here,
+    /// use [`Self::game`] instead.
+    pub fn for_any_supported_game() -> Result<Self, Error> {
+        Err(Error::InvalidParams(Self::GAME_REQUIRED))
+    }
+
+    /// Checks for parameters that the API is known to reject or misinterpret,
+    /// so that the mistake is reported clearly. Currently, this checks that
+    /// `game_id` is a positive ID, rather than `0` for "any game".
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.game_id <= 0 {
+            return Err(Error::InvalidParams(Self::GAME_REQUIRED));
+        }
+
+        Ok(())
+    }
+
     pub fn game(game_id: i32) -> Self {
         Self {
             game_id,
@@ -62,16 +112,102 @@ impl ProjectSearchParams {
             sort_order: None,
             mod_loader: None,
             game_version_type_id: None,
+            author_id: None,
             slug: None,
             index: None,
             page_size: None,
+            extra: BTreeMap::new(),
+        }
+    }
+
+    /// Add a parameter to [`Self::extra`].
+    pub fn extra<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+
+    /// Fills every field that is `None` with the value from `defaults`, and
+    /// adds every parameter in the `extra` of `defaults` that is not already
+    /// set. The defaults have no `game_id`, because every search is for its
+    /// own game.
+    pub fn with_defaults(self, defaults: &ProjectSearchDefaults) -> Self {
+        let mut extra = defaults.extra.clone();
+        extra.extend(self.extra);
+
+        Self {
+            game_id: self.game_id,
+            class_id: self.class_id.or(defaults.class_id),
+            category_id: self.category_id.or(defaults.category_id),
+            game_version: self.game_version.or_else(|| defaults.game_version.clone()),
+            search_filter: self
+                .search_filter
+                .or_else(|| defaults.search_filter.clone()),
+            sort_field: self.sort_field.or_else(|| defaults.sort_field.clone()),
+            sort_order: self.sort_order.or_else(|| defaults.sort_order.clone()),
+            mod_loader: self.mod_loader.or_else(|| defaults.mod_loader.clone()),
+            game_version_type_id: self.game_version_type_id.or(defaults.game_version_type_id),
+            author_id: self.author_id.or(defaults.author_id),
+            slug: self.slug.or_else(|| defaults.slug.clone()),
+            index: self.index.or(defaults.index),
+            page_size: self.page_size.or(defaults.page_size),
+            extra,
         }
     }
+
+    /// Serializes these parameters to the query string that is sent with the
+    /// request. The parameters in [`Self::extra`] are appended after the
+    /// fields, in order by name, unless a field has already been sent with
+    /// the same name.
+    pub fn query_string(&self) -> String {
+        // Use of unwrap: every field serializes to a flat value.
+        let query = serde_qs::to_string(self).unwrap();
+        let known = url::form_urlencoded::parse(query.as_bytes())
+            .map(|(key, _)| key.into_owned())
+            .collect::<BTreeSet<_>>();
+
+        let mut serializer = url::form_urlencoded::Serializer::new(query);
+
+        for (key, value) in &self.extra {
+            if !known.contains(key) {
+                serializer.append_pair(key, value);
+            }
+        }
+
+        serializer.finish()
+    }
+}
+
+/// The fields of [`ProjectSearchParams`] other than `game_id`, to be merged
+/// into the parameters of searches for any game, see
+/// [`ProjectSearchParams::with_defaults`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSearchDefaults {
+    pub class_id: Option<i32>,
+    pub category_id: Option<i32>,
+    pub game_version: Option<String>,
+    pub search_filter: Option<String>,
+    pub sort_field: Option<SearchSort>,
+    pub sort_order: Option<SearchSortOrder>,
+    #[serde(rename = "modLoaderType")]
+    pub mod_loader: Option<ModLoaderType>,
+    pub game_version_type_id: Option<i32>,
+    pub author_id: Option<i32>,
+    pub slug: Option<String>,
+    pub index: Option<i32>,
+    pub page_size: Option<i32>,
+    #[serde(default)]
+    pub extra: BTreeMap<String, String>,
 }
 
 /// <https://docs.curseforge.com/#tocS_ModsSearchSortField>
 #[derive(Clone, Debug, PartialEq, Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum SearchSort {
     Featured = 1,
     Popularity = 2,
@@ -93,17 +229,49 @@ pub enum SearchSortOrder {
 }
 
 /// <https://docs.curseforge.com/#get-mod-files>
-#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+///
+/// The fields `game_version`, `mod_loader`, and `game_version_type_id` are
+/// filters applied by the API. The API does not support filtering by release
+/// type, so `release_type` is never sent, and is instead applied to the items
+/// yielded by [`Client::project_files_iter`]. It has no effect on a single page
+/// requested with [`Client::project_files`].
+///
+/// [`Client::project_files_iter`]: crate::official::client::Client::project_files_iter
+/// [`Client::project_files`]: crate::official::client::Client::project_files
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectFilesParams {
     pub game_version: Option<String>,
     #[serde(rename = "modLoaderType")]
     pub mod_loader: Option<ModLoaderType>,
     pub game_version_type_id: Option<i32>,
+    #[serde(default, skip_serializing)]
+    pub release_type: Option<FileReleaseType>,
     pub index: Option<i32>,
     pub page_size: Option<i32>,
 }
 
+impl ProjectFilesParams {
+    /// Only yield files with the given `release_type`, see the notes above on
+    /// where this filter is applied.
+    pub fn release_type(mut self, release_type: FileReleaseType) -> Self {
+        self.release_type = Some(release_type);
+        self
+    }
+
+    /// Fills every field that is `None` with the value from `defaults`.
+    pub fn with_defaults(self, defaults: &Self) -> Self {
+        Self {
+            game_version: self.game_version.or_else(|| defaults.game_version.clone()),
+            mod_loader: self.mod_loader.or_else(|| defaults.mod_loader.clone()),
+            game_version_type_id: self.game_version_type_id.or(defaults.game_version_type_id),
+            release_type: self.release_type.or_else(|| defaults.release_type.clone()),
+            index: self.index.or(defaults.index),
+            page_size: self.page_size.or(defaults.page_size),
+        }
+    }
+}
+
 macro_rules! several_body {
     ($field:literal, $field_type:ty, $iter:expr) => {{
         use serde::Serialize;
@@ -123,10 +291,11 @@ macro_rules! several_body {
 pub(crate) use several_body;
 
 /// <https://docs.curseforge.com/#tocS_GetFeaturedModsRequestBody>
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FeaturedProjectsBody {
     pub game_id: i32,
+    #[serde(default)]
     pub excluded_mod_ids: Vec<i32>,
     pub game_version_type_id: Option<i32>,
 }
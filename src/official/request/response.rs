@@ -1,7 +1,13 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
+use std::time::Duration;
 
+use isahc::http::HeaderMap;
+
+use crate::official::rate_limit::RateLimitInfo;
 use crate::official::types::core::Pagination;
+use crate::official::types::projects::{ModLoaderType, Project};
 
 /// Wraps API responses which have the single field `data`.
 ///
@@ -9,25 +15,43 @@ use crate::official::types::core::Pagination;
 /// methods that make calls to endpoints returning this type will unwrap the
 /// contents and return the value of `data` directly.
 ///
-/// | [`Client`] Methods            | API Reference                |
-/// | ----------------------------- | ---------------------------- |
-/// | [`game`]                      | [Get Game Response]          |
-/// | [`game_versions`]             | [Get Versions Response]      |
-/// | [`game_version_types`]        | [Get Version Types Response] |
-/// | [`categories`]                | [Get Categories Response]    |
-/// | [`project`]                   | [Get Mod Response]           |
-/// | [`projects`]                  | [Get Mods Response]          |
-/// | [`featured_projects`]         | [Get Featured Mods Response] |
-/// | [`project_description`]       | [String Response]            |
-/// | [`project_file`]              | [Get Mod File Response]      |
-/// | [`project_files_by_ids`]      | [Get Files Response]         |
-/// | [`project_file_changelog`]    | [String Response]            |
-/// | [`project_file_download_url`] | [String Response]            |
+/// | [`Client`] Methods               | API Reference                      |
+/// | -------------------------------- | ---------------------------------- |
+/// | [`game`]                         | [Get Game Response]                |
+/// | [`game_versions`]                | [Get Versions Response]            |
+/// | [`game_version_types`]           | [Get Version Types Response]       |
+/// | [`minecraft_versions`]           | [Minecraft Versions Response]      |
+/// | [`minecraft_modloaders`]         | [Minecraft Modloaders Response]    |
+/// | [`minecraft_modloader`]          | [Minecraft Modloader Response]     |
+/// | [`categories`]                   | [Get Categories Response]          |
+/// | [`project`]                      | [Get Mod Response]                 |
+/// | [`projects`]                     | [Get Mods Response]                |
+/// | [`featured_projects`]            | [Get Featured Mods Response]       |
+/// | [`project_description`]          | [String Response]                  |
+/// | [`project_file`]                 | [Get Mod File Response]            |
+/// | [`project_files_by_ids`]         | [Get Files Response]               |
+/// | [`project_file_changelog`]       | [String Response]                  |
+/// | [`project_file_download_url`]    | [String Response]                  |
+/// | [`fingerprint_matches`]          | [Get Fingerprint Matches Response] |
+/// | [`fingerprint_matches_for_game`] | [Get Fingerprint Matches Response] |
+///
+/// Some proxies respond with an empty body, with the status `200: OK` or `204:
+/// No Content`, when there are no results. This is treated as an empty list by
+/// the methods that return one, which are [`game_versions`],
+/// [`game_version_types`], [`minecraft_versions`], [`minecraft_modloaders`],
+/// [`categories`], [`projects`], and [`project_files_by_ids`]. The other
+/// methods return [`Error::Deserialize`] or [`Error::StatusNotOk`] for such a
+/// response.
 ///
 /// [`Client`]: crate::official::client::Client
+/// [`Error::Deserialize`]: crate::Error::Deserialize
+/// [`Error::StatusNotOk`]: crate::Error::StatusNotOk
 /// [`game`]: crate::official::client::Client::game
 /// [`game_versions`]: crate::official::client::Client::game_versions
 /// [`game_version_types`]: crate::official::client::Client::game_version_types
+/// [`minecraft_versions`]: crate::official::client::Client::minecraft_versions
+/// [`minecraft_modloaders`]: crate::official::client::Client::minecraft_modloaders
+/// [`minecraft_modloader`]: crate::official::client::Client::minecraft_modloader
 /// [`categories`]: crate::official::client::Client::categories
 /// [`project`]: crate::official::client::Client::project
 /// [`projects`]: crate::official::client::Client::projects
@@ -37,10 +61,15 @@ use crate::official::types::core::Pagination;
 /// [`project_files_by_ids`]: crate::official::client::Client::project_files_by_ids
 /// [`project_file_changelog`]: crate::official::client::Client::project_file_changelog
 /// [`project_file_download_url`]: crate::official::client::Client::project_file_download_url
+/// [`fingerprint_matches`]: crate::official::client::Client::fingerprint_matches
+/// [`fingerprint_matches_for_game`]: crate::official::client::Client::fingerprint_matches_for_game
 ///
 /// [Get Game response]: https://docs.curseforge.com/#tocS_Get%20Game%20Response
 /// [Get Versions Response]: https://docs.curseforge.com/#tocS_Get%20Versions%20Response
 /// [Get Version Types Response]: https://docs.curseforge.com/#tocS_Get%20Version%20Types%20Response
+/// [Minecraft Versions Response]: https://docs.curseforge.com/#tocS_ApiResponseOfListOfMinecraftGameVersion
+/// [Minecraft Modloaders Response]: https://docs.curseforge.com/#tocS_ApiResponseOfListOfMinecraftModLoaderIndex
+/// [Minecraft Modloader Response]: https://docs.curseforge.com/#tocS_ApiResponseOfMinecraftModLoaderVersion
 /// [Get Categories Response]: https://docs.curseforge.com/#tocS_Get%20Categories%20Response
 /// [Get Mod Response]: https://docs.curseforge.com/#tocS_Get%20Mod%20Response
 /// [Get Mods Response]: https://docs.curseforge.com/#tocS_Get%20Mods%20Response
@@ -48,7 +77,9 @@ use crate::official::types::core::Pagination;
 /// [Get Mod File Response]: https://docs.curseforge.com/#tocS_Get%20Mod%20File%20Response
 /// [Get Files Response]: https://docs.curseforge.com/#tocS_Get%20Files%20Response
 /// [String Response]: https://docs.curseforge.com/#tocS_String%20Response
+/// [Get Fingerprint Matches Response]: https://docs.curseforge.com/#tocS_Get%20Fingerprint%20Matches%20Response
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DataResponse<T> {
     pub data: T,
@@ -57,6 +88,17 @@ pub struct DataResponse<T> {
     pub other_fields: serde_json::Value,
 }
 
+impl<T> DataResponse<T> {
+    /// Wraps a value that was not deserialized from a response.
+    pub(crate) fn new(data: T) -> Self {
+        Self {
+            data,
+            #[cfg(feature = "allow-unknown-fields")]
+            other_fields: serde_json::Value::Object(Default::default()),
+        }
+    }
+}
+
 impl<T> Deref for DataResponse<T> {
     type Target = T;
 
@@ -78,27 +120,34 @@ impl<T> DerefMut for DataResponse<T> {
 /// than those that return this type directly, as pagination over the results
 /// can be tricky to get right.
 ///
-/// | [`Client`] Methods       | API Reference            |
-/// | ------------------------ | ------------------------ |
-/// | [`games`]                | [Get Games Response]     |
-/// | [`games_iter`]           | [Get Games Response]     |
-/// | [`search_projects`]      | [Search Mods Response]   |
-/// | [`search_projects_iter`] | [Search Mods Response]   |
-/// | [`project_files`]        | [Get Mod Files Response] |
-/// | [`project_files_iter`]   | [Get Mod Files Response] |
+/// | [`Client`] Methods            | API Reference            |
+/// | ----------------------------- | ------------------------ |
+/// | [`games`]                     | [Get Games Response]     |
+/// | [`games_iter`]                | [Get Games Response]     |
+/// | [`search_projects`]           | [Search Mods Response]   |
+/// | [`search_projects_raw_query`] | [Search Mods Response]   |
+/// | [`search_projects_iter`]      | [Search Mods Response]   |
+/// | [`search_projects_iter_from`] | [Search Mods Response]   |
+/// | [`project_files`]             | [Get Mod Files Response] |
+/// | [`project_files_iter`]        | [Get Mod Files Response] |
+/// | [`project_files_iter_from`]   | [Get Mod Files Response] |
 ///
 /// [`Client`]: crate::official::client::Client
 /// [`games`]: crate::official::client::Client::games
 /// [`games_iter`]: crate::official::client::Client::games_iter
 /// [`search_projects`]: crate::official::client::Client::search_projects
+/// [`search_projects_raw_query`]: crate::official::client::Client::search_projects_raw_query
 /// [`search_projects_iter`]: crate::official::client::Client::search_projects_iter
+/// [`search_projects_iter_from`]: crate::official::client::Client::search_projects_iter_from
 /// [`project_files`]: crate::official::client::Client::project_files
 /// [`project_files_iter`]: crate::official::client::Client::project_files_iter
+/// [`project_files_iter_from`]: crate::official::client::Client::project_files_iter_from
 ///
 /// [Get Games Response]: https://docs.curseforge.com/#tocS_Get%20Games%20Response
 /// [Search Mods Response]: https://docs.curseforge.com/#tocS_Search%20Mods%20Response
 /// [Get Mod Files Response]: https://docs.curseforge.com/#tocS_Get%20Mod%20Files%20Response
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PaginatedDataResponse<T> {
     pub data: Vec<T>,
@@ -108,17 +157,34 @@ pub struct PaginatedDataResponse<T> {
     pub other_fields: serde_json::Value,
 }
 
-/// This type is a pair of a response's body bytes and the deserialized value.
-/// It usually wraps a [`DataResponse`] or a [`PaginatedDataResponse`], for
-/// instance when returned from a method in [`crate::official::endpoints`]. In
-/// these cases, use the [`ApiDataResult`] and [`ApiPageResult`] aliases.
+/// This type is a pair of a response's body bytes and the deserialized value,
+/// along with the headers of the response. It usually wraps a [`DataResponse`]
+/// or a [`PaginatedDataResponse`], for instance when returned from a method in
+/// [`crate::official::endpoints`]. In these cases, use the [`ApiDataResult`]
+/// and [`ApiPageResult`] aliases.
 #[derive(Debug)]
 pub struct ApiResponse<T> {
     pub(crate) bytes: Vec<u8>,
     pub(crate) value: T,
+    pub(crate) elapsed: Duration,
+    pub(crate) headers: HeaderMap,
 }
 
 impl<T> ApiResponse<T> {
+    /// Pairs `value` with the `bytes` that it is meant to have been
+    /// deserialized from, for use in tests. The elapsed time is zero, and
+    /// there are no headers. See also [`crate::official::testing`].
+    #[cfg(feature = "testing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+    pub fn from_parts(bytes: Vec<u8>, value: T) -> Self {
+        Self {
+            bytes,
+            value,
+            elapsed: Duration::ZERO,
+            headers: HeaderMap::new(),
+        }
+    }
+
     /// Get an immutable borrow to the response's body bytes.
     pub fn get_bytes(&self) -> &[u8] {
         &self.bytes
@@ -139,6 +205,25 @@ impl<T> ApiResponse<T> {
         &mut self.value
     }
 
+    /// Get the time that was spent sending the request and reading the body
+    /// of the response. This does not include deserialization.
+    pub fn get_elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Get an immutable borrow to the response's headers.
+    pub fn get_headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Get the number of requests left in the current rate limit window, from
+    /// the `x-ratelimit-remaining` header. Returns `None` whenever
+    /// [`RateLimitInfo::from_headers`] would, not every API host sends the
+    /// headers.
+    pub fn rate_limit_remaining(&self) -> Option<u64> {
+        RateLimitInfo::from_headers(&self.headers).map(|info| info.remaining)
+    }
+
     /// Take out the response's body bytes, discarding the deserialized data.
     pub fn into_bytes(self) -> Vec<u8> {
         self.bytes
@@ -169,7 +254,133 @@ impl<T> DerefMut for ApiResponse<T> {
     }
 }
 
+/// A value paired with the time that was spent sending the request and reading
+/// the response that it was deserialized from. This is returned by the methods
+/// of [`TimedClient`], see [`Client::timed`].
+///
+/// [`TimedClient`]: crate::official::client::TimedClient
+/// [`Client::timed`]: crate::official::client::Client::timed
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timed<T> {
+    pub value: T,
+    pub elapsed: Duration,
+}
+
+impl<T> Deref for Timed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Timed<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+/// A page of search results along with facet counts that were computed from
+/// the projects in it. This is returned by [`Client::search_with_facets`].
+///
+/// The API does not provide facets, so **the counts only reflect the projects
+/// on the current page**, not every result of the search.
+///
+/// [`Client::search_with_facets`]: crate::official::client::Client::search_with_facets
+#[derive(Clone, Debug, PartialEq)]
+pub struct FacetedResults {
+    pub projects: Vec<Project>,
+    pub pagination: Pagination,
+    /// The number of projects on this page with each `primary_category_id`.
+    pub category_counts: HashMap<u32, usize>,
+    /// The number of projects on this page that support each loader, as
+    /// determined by [`Project::supported_loaders`].
+    pub loader_counts: HashMap<ModLoaderType, usize>,
+}
+
+impl From<PaginatedDataResponse<Project>> for FacetedResults {
+    fn from(other: PaginatedDataResponse<Project>) -> Self {
+        let mut category_counts = HashMap::new();
+        let mut loader_counts = HashMap::new();
+
+        for project in &other.data {
+            *category_counts
+                .entry(project.primary_category_id)
+                .or_default() += 1;
+
+            for loader in project.supported_loaders() {
+                *loader_counts.entry(loader).or_default() += 1;
+            }
+        }
+
+        Self {
+            projects: other.data,
+            pagination: other.pagination,
+            category_counts,
+            loader_counts,
+        }
+    }
+}
+
 /// See the documentation for [`ApiResponse`].
 pub type ApiDataResult<T> = Result<ApiResponse<DataResponse<T>>, crate::Error>;
 /// See the documentation for [`ApiResponse`].
 pub type ApiPageResult<T> = Result<ApiResponse<PaginatedDataResponse<T>>, crate::Error>;
+
+/// Adds methods to the results of the functions in
+/// [`crate::official::endpoints`], to take out the deserialized value in the
+/// same shape as the corresponding [`Client`] methods return it.
+///
+/// ```no_run
+/// # use curseforge::official::prelude::*;
+/// # async fn example(client: &isahc::HttpClient, base: &url::Url) -> Result<(), curseforge::Error> {
+/// let game: Game = e::game(client, base, 432).await.data()?;
+/// let page = e::games(client, base, &GamesParams::default()).await.page()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Client`]: crate::official::client::Client
+pub trait ApiResponseExt {
+    /// The type of the `data` field of the response.
+    type Data;
+
+    /// Take out the `data` field of the response, discarding the bytes and
+    /// any other fields.
+    fn data(self) -> Result<Self::Data, crate::Error>;
+}
+
+impl<T> ApiResponseExt for ApiDataResult<T> {
+    type Data = T;
+
+    fn data(self) -> Result<T, crate::Error> {
+        self.map(|r| r.value.data)
+    }
+}
+
+impl<T> ApiResponseExt for ApiPageResult<T> {
+    type Data = Vec<T>;
+
+    fn data(self) -> Result<Vec<T>, crate::Error> {
+        self.map(|r| r.value.data)
+    }
+}
+
+/// Adds a method to the results of the paginated functions in
+/// [`crate::official::endpoints`], see [`ApiResponseExt`].
+pub trait ApiPageResponseExt {
+    /// The type of the items on the page.
+    type Item;
+
+    /// Take out the page, with its `data` and `pagination`, discarding the
+    /// bytes.
+    fn page(self) -> Result<PaginatedDataResponse<Self::Item>, crate::Error>;
+}
+
+impl<T> ApiPageResponseExt for ApiPageResult<T> {
+    type Item = T;
+
+    fn page(self) -> Result<PaginatedDataResponse<T>, crate::Error> {
+        self.map(|r| r.value)
+    }
+}
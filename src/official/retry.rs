@@ -0,0 +1,189 @@
+//! Contains [`RetryConfig`], and the interceptor that retries requests for
+//! [`ClientOptions::retry`].
+//!
+//! [`ClientOptions::retry`]: crate::official::client::ClientOptions::retry
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use isahc::http::header::RETRY_AFTER;
+use isahc::http::{HeaderMap, Method, Request, Response};
+use isahc::interceptor::{Context, Interceptor, InterceptorFuture};
+use isahc::AsyncBody;
+
+/// Settings for retrying requests that have failed in a way that may succeed
+/// if they are sent again, see [`ClientOptions::retry`].
+///
+/// Only `GET` requests are retried, when the response status is `429: Too Many
+/// Requests` or any `5xx`, or when the connection failed or timed out. The
+/// delay before each retry starts at `base_delay` and doubles with each
+/// attempt. When the response has a `Retry-After` header, either as a number
+/// of seconds or as a date, the delay that it asks for is used instead. No
+/// delay is longer than `max_delay`; when the server asks for a longer one,
+/// the response is returned as it is, without retrying.
+///
+/// Every retry made by a client is also drawn from a single budget, shared by
+/// all of its requests (including clones of the client). The budget holds at
+/// most `budget` retries, and regains `budget_refill_per_second` of them each
+/// second. When it is empty, a failed request is returned as it is, without
+/// retrying, so that an outage does not cause every request to retry at once.
+///
+/// [`ClientOptions::retry`]: crate::official::client::ClientOptions::retry
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryConfig {
+    /// The maximum number of times a single request is retried.
+    pub max_retries: u32,
+    /// The delay before the first retry of a request.
+    pub base_delay: Duration,
+    /// The longest delay before any retry.
+    pub max_delay: Duration,
+    /// The maximum number of retries that may be made by all requests in a
+    /// burst.
+    pub budget: u32,
+    /// The number of retries returned to the budget each second.
+    pub budget_refill_per_second: f64,
+}
+
+impl RetryConfig {
+    /// Returns the delay before retry number `attempt`, counting from `0`,
+    /// when the response does not ask for one. This is `base_delay` doubled
+    /// for each earlier attempt, and no longer than `max_delay`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_delay)
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+            budget: 10,
+            budget_refill_per_second: 1.0,
+        }
+    }
+}
+
+/// The state of the token bucket that retries are drawn from.
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
+}
+
+/// Retries requests according to a [`RetryConfig`].
+#[derive(Debug)]
+pub(crate) struct RetryInterceptor {
+    config: RetryConfig,
+    bucket: Mutex<Bucket>,
+}
+
+impl RetryInterceptor {
+    pub(crate) fn new(config: RetryConfig) -> Self {
+        Self {
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(config.budget),
+                refilled: Instant::now(),
+            }),
+            config,
+        }
+    }
+
+    /// Takes a retry from the budget, returning `false` if there is none.
+    fn withdraw(&self) -> bool {
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        let refill = now.duration_since(bucket.refilled).as_secs_f64()
+            * self.config.budget_refill_per_second;
+
+        bucket.tokens = (bucket.tokens + refill).min(f64::from(self.config.budget));
+        bucket.refilled = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+fn is_retryable(result: &Result<Response<AsyncBody>, isahc::Error>) -> bool {
+    match result {
+        Ok(response) => response.status() == 429 || response.status().is_server_error(),
+        Err(error) => error.is_network() || error.is_timeout(),
+    }
+}
+
+/// Parses the `Retry-After` header, which is either a number of seconds or an
+/// HTTP date. A date in the past is no delay at all.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// Copies a request that has no body, so that it can be sent again. This
+/// keeps the configuration that the client has attached to the request.
+fn copy_request(request: &Request<AsyncBody>) -> Result<Request<AsyncBody>, isahc::Error> {
+    use isahc::RequestExt;
+
+    Ok(request.to_builder().body(AsyncBody::empty())?)
+}
+
+impl Interceptor for RetryInterceptor {
+    type Err = isahc::Error;
+
+    fn intercept<'a>(
+        &'a self,
+        request: Request<AsyncBody>,
+        ctx: Context<'a>,
+    ) -> InterceptorFuture<'a, Self::Err> {
+        Box::pin(async move {
+            if request.method() != Method::GET {
+                return ctx.send(request).await;
+            }
+
+            let mut attempt = 0;
+
+            loop {
+                let result = ctx.send(copy_request(&request)?).await;
+
+                if attempt >= self.config.max_retries || !is_retryable(&result) {
+                    return result;
+                }
+
+                let retry_after = result
+                    .as_ref()
+                    .ok()
+                    .and_then(|response| retry_after(response.headers()));
+                let delay = match retry_after {
+                    Some(delay) if delay > self.config.max_delay => return result,
+                    Some(delay) => delay,
+                    None => self.config.backoff(attempt),
+                };
+
+                if !self.withdraw() {
+                    return result;
+                }
+
+                futures_timer::Delay::new(delay).await;
+                attempt += 1;
+            }
+        })
+    }
+}
@@ -0,0 +1,68 @@
+//! Helpers for the tests of crates that depend on this one, to build values of
+//! this crate's types without making any requests. This module is only
+//! available with the `testing` feature, which is meant to be enabled in
+//! `[dev-dependencies]`.
+//!
+//! ```
+//! use curseforge::official::testing;
+//!
+//! let json = r#"{ "data": "<p>Changes</p>" }"#;
+//! let response = testing::data_response::<String>(json).unwrap();
+//!
+//! assert_eq!(response.get_bytes(), json.as_bytes());
+//! assert_eq!(response.data, "<p>Changes</p>");
+//! ```
+
+use serde::de::DeserializeOwned;
+
+use crate::official::request::{ApiResponse, DataResponse, PaginatedDataResponse};
+use crate::official::types::{Project, ProjectFile};
+
+/// The error returned when a value could not be deserialized, with the path
+/// to the field that caused it.
+pub type JsonError = serde_path_to_error::Error<serde_json::Error>;
+
+/// Deserializes any type of this crate from a string of JSON, in the format
+/// that the API sends it.
+pub fn from_json<T>(json: &str) -> Result<T, JsonError>
+where
+    T: DeserializeOwned,
+{
+    let deser = &mut serde_json::Deserializer::from_str(json);
+
+    serde_path_to_error::deserialize(deser)
+}
+
+/// Deserializes a [`Project`] from a string of JSON, such as the value of
+/// `data` in a response to [`e::project`].
+///
+/// [`e::project`]: crate::official::endpoints::project
+pub fn project(json: &str) -> Result<Project, JsonError> {
+    from_json(json)
+}
+
+/// Deserializes a [`ProjectFile`] from a string of JSON, such as the value of
+/// `data` in a response to [`e::project_file`].
+///
+/// [`e::project_file`]: crate::official::endpoints::project_file
+pub fn project_file(json: &str) -> Result<ProjectFile, JsonError> {
+    from_json(json)
+}
+
+/// Deserializes a whole response body that has a `data` field, and keeps the
+/// bytes along with it, as they are returned by [`crate::official::endpoints`].
+pub fn data_response<T>(json: &str) -> Result<ApiResponse<DataResponse<T>>, JsonError>
+where
+    T: DeserializeOwned,
+{
+    Ok(ApiResponse::from_parts(json.into(), from_json(json)?))
+}
+
+/// Deserializes a whole response body that has the fields `data` and
+/// `pagination`, and keeps the bytes along with it.
+pub fn paginated_response<T>(json: &str) -> Result<ApiResponse<PaginatedDataResponse<T>>, JsonError>
+where
+    T: DeserializeOwned,
+{
+    Ok(ApiResponse::from_parts(json.into(), from_json(json)?))
+}
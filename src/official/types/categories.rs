@@ -5,6 +5,7 @@ use super::fixes::deserialize_nullable_datetime;
 
 /// <https://docs.curseforge.com/#tocS_Category>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Category {
@@ -16,9 +17,11 @@ pub struct Category {
     pub icon_url: String,
     #[serde(deserialize_with = "deserialize_nullable_datetime")]
     pub date_modified: Option<DateTime<Utc>>,
+    #[serde(default)]
     pub is_class: Option<bool>,
     #[serde(default)]
     pub class_id: Option<i32>,
+    #[serde(default)]
     pub parent_category_id: Option<i32>,
     #[cfg(feature = "allow-unknown-fields")]
     #[serde(flatten)]
@@ -1,9 +1,13 @@
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
+use super::fixes::deserialize_count;
+
 /// <https://docs.curseforge.com/#tocS_CoreStatus>
 #[derive(Clone, Debug, PartialEq, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum CoreStatus {
     Draft = 1,
     Test = 2,
@@ -18,7 +22,9 @@ pub enum CoreStatus {
 
 /// <https://docs.curseforge.com/#tocS_CoreApiStatus>
 #[derive(Clone, Debug, PartialEq, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum CoreApiStatus {
     Private = 1,
     Public = 2,
@@ -29,11 +35,152 @@ pub enum CoreApiStatus {
 
 /// <https://docs.curseforge.com/#tocS_Pagination>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
-#[serde(deny_unknown_fields)]
+#[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Pagination {
     pub index: i32,
     pub page_size: i32,
+    /// This may also be received as a string.
+    #[serde(deserialize_with = "deserialize_count")]
     pub result_count: i32,
+    /// This may also be received as a string.
+    #[serde(deserialize_with = "deserialize_count")]
     pub total_count: i64,
+    #[cfg(feature = "allow-unknown-fields")]
+    #[serde(flatten)]
+    pub other_fields: serde_json::Value,
+}
+
+impl Pagination {
+    /// Returns the fraction of the results that have been received once this
+    /// page has been, between `0.0` and `1.0`, for use in progress bars.
+    ///
+    /// The total is capped at [`API_PAGINATION_RESULTS_LIMIT`], because no
+    /// results beyond it can be requested. If there are no results at all,
+    /// this is `1.0`. Comparing the progress of two pages tells which one is
+    /// further along.
+    ///
+    /// [`API_PAGINATION_RESULTS_LIMIT`]: crate::official::endpoints::API_PAGINATION_RESULTS_LIMIT
+    pub fn progress(&self) -> f32 {
+        use crate::official::endpoints::API_PAGINATION_RESULTS_LIMIT;
+
+        let total = self.total_count.min(API_PAGINATION_RESULTS_LIMIT as i64);
+
+        if total <= 0 {
+            return 1.0;
+        }
+
+        let received = i64::from(self.index) + i64::from(self.result_count);
+
+        (received as f32 / total as f32).clamp(0.0, 1.0)
+    }
+}
+
+/// Implements the conversions that let an HTML newtype be used in place of the
+/// [`String`] that it wraps.
+macro_rules! html_newtype {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+        #[serde(transparent)]
+        pub struct $name(pub String);
+
+        impl $name {
+            /// Get the HTML as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Unwraps the HTML into the [`String`] that it was received as.
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_bytes()
+            }
+        }
+
+        impl std::borrow::Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(other: String) -> Self {
+                Self(other)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(other: $name) -> Self {
+                other.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                &self.0 == other
+            }
+        }
+    };
+}
+
+html_newtype! {
+    /// The HTML description of a project, as returned by
+    /// [`Client::project_description_html`].
+    ///
+    /// This dereferences to [`str`], and converts into a [`String`] or bytes,
+    /// so that it can be used wherever the [`String`] that it wraps was.
+    ///
+    /// [`Client::project_description_html`]: crate::official::client::Client::project_description_html
+    Description
+}
+
+html_newtype! {
+    /// The HTML changelog of a project file, as returned by
+    /// [`Client::project_file_changelog_html`].
+    ///
+    /// This dereferences to [`str`], and converts into a [`String`] or bytes,
+    /// so that it can be used wherever the [`String`] that it wraps was.
+    ///
+    /// [`Client::project_file_changelog_html`]: crate::official::client::Client::project_file_changelog_html
+    Changelog
 }
@@ -1,18 +1,26 @@
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
-use super::fixes::deserialize_nullable_string;
-use super::projects::ModLoaderType;
+use super::fixes::{
+    deserialize_fingerprint, deserialize_id, deserialize_nullable_string, deserialize_optional_id,
+    deserialize_vec_or_empty_object,
+};
+use super::projects::{GameVersionTag, ModLoaderType};
 
 /// <https://docs.curseforge.com/#tocS_File>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ProjectFile {
+    #[serde(deserialize_with = "deserialize_id")]
     pub id: i32,
     pub game_id: i32,
-    #[serde(rename = "modId")]
+    #[serde(rename = "modId", deserialize_with = "deserialize_id")]
     pub project_id: i32,
     pub is_available: bool,
     pub display_name: String,
@@ -26,15 +34,22 @@ pub struct ProjectFile {
     pub download_url: Option<String>,
     pub game_versions: Vec<String>,
     pub sortable_game_versions: Vec<SortableGameVersion>,
+    /// Some proxies send an empty object or `null` when there are none, these
+    /// are treated as an empty list.
+    #[serde(deserialize_with = "deserialize_vec_or_empty_object")]
     pub dependencies: Vec<FileDependency>,
     #[serde(default)]
     pub expose_as_alternative: bool,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
     pub parent_project_file_id: Option<i32>,
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
     pub alternate_file_id: Option<i32>,
     pub is_server_pack: bool,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_optional_id")]
     pub server_pack_file_id: Option<i32>,
+    /// The MurmurHash2 of the file, which is an unsigned 32-bit value. This
+    /// may also be received as a string.
+    #[serde(deserialize_with = "deserialize_fingerprint")]
     pub file_fingerprint: i64,
     pub modules: Vec<FileModule>,
     #[cfg(feature = "allow-unknown-fields")]
@@ -42,12 +57,378 @@ pub struct ProjectFile {
     pub other_fields: serde_json::Value,
 }
 
+impl ProjectFile {
+    /// Classifies every entry of `game_versions`, and the name of every entry
+    /// of `sortable_game_versions`, according to the game that the file is
+    /// for. See [`GameVersionTag`]. Tags that appear in both are only included
+    /// once, in the order that they first appear.
+    pub fn game_version_tags(&self) -> Vec<GameVersionTag> {
+        let mut tags = Vec::new();
+        let names = self.game_versions.iter().map(String::as_str).chain(
+            self.sortable_game_versions
+                .iter()
+                .map(|version| version.game_version_name.as_str()),
+        );
+
+        for name in names {
+            let tag = GameVersionTag::classify(self.game_id, name);
+
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+
+        tags
+    }
+
+    /// Returns the `download_url` as an absolute URL, resolving it against the
+    /// CDN if a proxy has rewritten it to a relative path. See
+    /// [`resolve_download_url`]. Returns `Ok(None)` if there is no download
+    /// URL.
+    ///
+    /// [`resolve_download_url`]: crate::official::endpoints::resolve_download_url
+    pub fn resolved_download_url(&self) -> Result<Option<url::Url>, url::ParseError> {
+        self.download_url
+            .as_deref()
+            .map(crate::official::endpoints::resolve_download_url)
+            .transpose()
+    }
+
+    /// Returns the hex-encoded value of each of the file's `hashes`, by
+    /// algorithm. If the API listed an algorithm more than once, the first
+    /// value is kept.
+    pub fn hash_map(&self) -> HashMap<HashAlgorithm, &str> {
+        let mut map = HashMap::new();
+
+        for hash in &self.hashes {
+            map.entry(hash.algo.clone())
+                .or_insert_with(|| hash.value.as_str());
+        }
+
+        map
+    }
+
+    /// Returns the SHA-1 hash of the file, if the API provided one.
+    pub fn sha1(&self) -> Option<&str> {
+        self.hash(HashAlgorithm::Sha1)
+    }
+
+    /// Returns the MD5 hash of the file, if the API provided one.
+    pub fn md5(&self) -> Option<&str> {
+        self.hash(HashAlgorithm::Md5)
+    }
+
+    fn hash(&self, algo: HashAlgorithm) -> Option<&str> {
+        self.hashes
+            .iter()
+            .find(|hash| hash.algo == algo)
+            .map(|hash| hash.value.as_str())
+    }
+
+    /// Formats `file_length` with binary units, such as `12.3 MiB`, for
+    /// display. Lengths below 1 KiB are shown in whole bytes, larger ones with
+    /// one decimal place. A negative length is shown as `0 B`.
+    pub fn human_size(&self) -> String {
+        const UNITS: &[&str] = &["KiB", "MiB", "GiB", "TiB"];
+
+        let bytes = self.file_length.max(0);
+
+        if bytes < 1024 {
+            return format!("{} B", bytes);
+        }
+
+        let mut size = bytes as f64 / 1024.0;
+        let mut unit = 0;
+
+        // The unit is chosen by the size as it is displayed, so that a size
+        // just under the next unit is not rounded up to `1024.0` of this one.
+        while (size * 10.0).round() >= 10240.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+
+    /// Returns the time that has passed since `file_date`, for display as a
+    /// relative time. This is negative if the date is in the future, such as
+    /// when the local clock is behind.
+    pub fn age(&self) -> chrono::Duration {
+        Utc::now() - self.file_date
+    }
+
+    /// Checks that `bytes`, the downloaded contents of this file, match the
+    /// hashes that the API reported. The SHA-1 hash is checked when there is
+    /// one, otherwise the MD5 hash.
+    ///
+    /// Files without either are checked against the `file_length` and
+    /// `file_fingerprint` instead, see [`fingerprint`]. The API reports `0`
+    /// for some files whose length or fingerprint it does not know, and these
+    /// are not checked.
+    pub fn verify_hash(&self, bytes: &[u8]) -> Result<(), HashMismatch> {
+        self.verify_reader(std::io::Cursor::new(bytes))
+    }
+
+    /// Checks the contents of this file that are read from `reader`, in the
+    /// same way as [`Self::verify_hash`], without holding them in memory. The
+    /// contents are read in chunks from the current position to the end, and
+    /// when the fingerprint is checked, they are read a second time.
+    pub fn verify_reader<R>(&self, mut reader: R) -> Result<(), HashMismatch>
+    where
+        R: Read + Seek,
+    {
+        if let Some(expected) = self.sha1() {
+            let mut hasher = sha1_smol::Sha1::new();
+            read_chunks(&mut reader, |chunk| hasher.update(chunk))?;
+            let actual = hasher.digest().to_string();
+
+            return match expected.eq_ignore_ascii_case(&actual) {
+                true => Ok(()),
+                false => Err(HashMismatch::Sha1 {
+                    expected: expected.to_owned(),
+                    actual,
+                }),
+            };
+        }
+
+        if let Some(expected) = self.md5() {
+            let mut context = md5::Context::new();
+            read_chunks(&mut reader, |chunk| context.consume(chunk))?;
+            let actual = format!("{:x}", context.compute());
+
+            return match expected.eq_ignore_ascii_case(&actual) {
+                true => Ok(()),
+                false => Err(HashMismatch::Md5 {
+                    expected: expected.to_owned(),
+                    actual,
+                }),
+            };
+        }
+
+        let start = reader.stream_position()?;
+        let mut length = 0;
+        let mut hashed = 0;
+
+        read_chunks(&mut reader, |chunk| {
+            length += chunk.len() as u64;
+            hashed += chunk.iter().filter(|byte| !is_skipped(**byte)).count();
+        })?;
+
+        if self.file_length > 0 && self.file_length != length as i64 {
+            return Err(HashMismatch::Length {
+                expected: self.file_length,
+                actual: length,
+            });
+        }
+
+        if self.file_fingerprint == 0 {
+            return Ok(());
+        }
+
+        let mut hasher = Fingerprinter::new(hashed);
+        reader.seek(SeekFrom::Start(start))?;
+        read_chunks(&mut reader, |chunk| hasher.update(chunk))?;
+        let actual = hasher.finish();
+
+        if self.file_fingerprint != i64::from(actual) {
+            return Err(HashMismatch::Fingerprint {
+                expected: self.file_fingerprint,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns `file_name` in a form that is safe to use as the name of a file
+    /// on any common filesystem, for when a file is written to disk.
+    ///
+    /// - Path separators (`/` and `\`), the characters reserved by Windows
+    ///   (`<>:"|?*`), and control characters are each replaced by `_`.
+    /// - Trailing dots and spaces are removed, Windows does not allow them.
+    /// - Names whose stem is reserved by Windows (such as `CON` or `LPT1`) are
+    ///   prefixed with `_`.
+    /// - Names made up only of dots and spaces (such as `..`) become `_`, and
+    ///   an empty name is replaced with the file ID.
+    ///
+    /// The extension is otherwise preserved, and `file_name` is not modified.
+    pub fn safe_filename(&self) -> String {
+        const RESERVED_STEMS: &[&str] = &[
+            "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7",
+            "COM8", "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+        ];
+
+        let replaced = self
+            .file_name
+            .chars()
+            .map(|c| match c {
+                '/' | '\\' | '<' | '>' | ':' | '"' | '|' | '?' | '*' => '_',
+                c if c.is_control() => '_',
+                c => c,
+            })
+            .collect::<String>();
+        let name = replaced.trim_end_matches(['.', ' ']);
+
+        if name.is_empty() {
+            return if replaced.is_empty() {
+                self.id.to_string()
+            } else {
+                "_".to_owned()
+            };
+        }
+
+        let stem = name.split('.').next().unwrap_or(name);
+
+        if RESERVED_STEMS
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(stem))
+        {
+            format!("_{}", name)
+        } else {
+            name.to_owned()
+        }
+    }
+}
+
+/// Sums the `file_length` of every file in `files`, in bytes, such as to show
+/// the size of a download before it starts.
+///
+/// The API reports a length of `0` for some files that it does not know the
+/// size of, and such files add nothing to the total, as do negative lengths.
+/// The total is then less than the size that is actually downloaded.
+pub fn total_download_size(files: &[ProjectFile]) -> i64 {
+    files.iter().map(|file| file.file_length.max(0)).sum()
+}
+
+/// Computes the fingerprint of the contents of a file in the same way as
+/// CurseForge, for comparison with [`ProjectFile::file_fingerprint`] or for
+/// [`Client::fingerprint_matches`].
+///
+/// This is the 32-bit MurmurHash2 of `bytes`, with a seed of `1`, after the
+/// whitespace bytes (tab, line feed, carriage return, and space) have been
+/// removed.
+///
+/// [`Client::fingerprint_matches`]: crate::official::client::Client::fingerprint_matches
+pub fn fingerprint(bytes: &[u8]) -> u32 {
+    let mut hasher = Fingerprinter::new(bytes.iter().filter(|byte| !is_skipped(**byte)).count());
+    hasher.update(bytes);
+    hasher.finish()
+}
+
+/// Whether `byte` is one of the whitespace bytes that [`fingerprint`] skips.
+fn is_skipped(byte: u8) -> bool {
+    matches!(byte, b'\t' | b'\n' | b'\r' | b' ')
+}
+
+/// Computes a [`fingerprint`] from contents that are given in chunks. The
+/// number of bytes that are hashed is part of the seed, so it must be known
+/// before the first chunk.
+struct Fingerprinter {
+    hash: u32,
+    tail: [u8; 4],
+    tail_len: usize,
+}
+
+impl Fingerprinter {
+    const M: u32 = 0x5bd1_e995;
+    const R: u32 = 24;
+
+    fn new(hashed: usize) -> Self {
+        Self {
+            hash: 1 ^ hashed as u32,
+            tail: [0; 4],
+            tail_len: 0,
+        }
+    }
+
+    fn update(&mut self, chunk: &[u8]) {
+        for byte in chunk.iter().copied().filter(|byte| !is_skipped(*byte)) {
+            self.tail[self.tail_len] = byte;
+            self.tail_len += 1;
+
+            if self.tail_len == 4 {
+                let mut k = u32::from_le_bytes(self.tail);
+
+                k = k.wrapping_mul(Self::M);
+                k ^= k >> Self::R;
+                k = k.wrapping_mul(Self::M);
+                self.hash = self.hash.wrapping_mul(Self::M) ^ k;
+                self.tail_len = 0;
+            }
+        }
+    }
+
+    fn finish(self) -> u32 {
+        let mut hash = self.hash;
+
+        if self.tail_len > 0 {
+            for (index, byte) in self.tail[..self.tail_len].iter().enumerate() {
+                hash ^= u32::from(*byte) << (8 * index);
+            }
+
+            hash = hash.wrapping_mul(Self::M);
+        }
+
+        hash ^= hash >> 13;
+        hash = hash.wrapping_mul(Self::M);
+        hash ^ (hash >> 15)
+    }
+}
+
+/// Reads `reader` to the end in chunks, giving each to `f`.
+fn read_chunks<R, F>(reader: &mut R, mut f: F) -> std::io::Result<()>
+where
+    R: Read,
+    F: FnMut(&[u8]),
+{
+    let mut buffer = vec![0; 64 * 1024];
+
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read) => f(&buffer[..read]),
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => (),
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// The way in which the contents of a file do not match its [`ProjectFile`],
+/// see [`ProjectFile::verify_hash`].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum HashMismatch {
+    /// The file could not be read, such as when it was never downloaded.
+    #[error("the file could not be read\n{0}")]
+    Io(#[from] std::io::Error),
+    /// The SHA-1 hash of the file is not the one that the API reported. Both
+    /// are hex-encoded.
+    #[error("expected the SHA-1 hash {expected}, found {actual}")]
+    Sha1 { expected: String, actual: String },
+    /// The MD5 hash of the file is not the one that the API reported. Both are
+    /// hex-encoded.
+    #[error("expected the MD5 hash {expected}, found {actual}")]
+    Md5 { expected: String, actual: String },
+    /// The file does not have the length that the API reported.
+    #[error("expected a length of {expected} bytes, found {actual}")]
+    Length { expected: i64, actual: u64 },
+    /// The file does not have the fingerprint that the API reported.
+    #[error("expected the fingerprint {expected}, found {actual}")]
+    Fingerprint { expected: i64, actual: u32 },
+    /// The API did not return the file, such as when it has been deleted, so
+    /// there is nothing to check it against.
+    #[error("the file was not returned by the API")]
+    Unresolved,
+}
+
 /// <https://docs.curseforge.com/#tocS_FileIndex>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct FileIndex {
     pub game_version: String,
+    #[serde(deserialize_with = "deserialize_id")]
     pub file_id: i32,
     pub filename: String,
     pub release_type: FileReleaseType,
@@ -60,7 +441,9 @@ pub struct FileIndex {
 
 /// <https://docs.curseforge.com/#tocS_FileReleaseType>
 #[derive(Clone, Debug, PartialEq, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum FileReleaseType {
     Release = 1,
     Beta = 2,
@@ -72,7 +455,9 @@ pub enum FileReleaseType {
 
 /// <https://docs.curseforge.com/#tocS_FileStatus>
 #[derive(Clone, Debug, PartialEq, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum FileStatus {
     Processing = 1,
     ChangesRequired = 2,
@@ -96,6 +481,7 @@ pub enum FileStatus {
 
 /// <https://docs.curseforge.com/#tocS_FileHash>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct FileHash {
@@ -107,8 +493,10 @@ pub struct FileHash {
 }
 
 /// <https://docs.curseforge.com/#tocS_HashAlgo>
-#[derive(Clone, Debug, PartialEq, Serialize_repr, Deserialize_repr)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum HashAlgorithm {
     Sha1 = 1,
     Md5 = 2,
@@ -119,6 +507,7 @@ pub enum HashAlgorithm {
 
 /// <https://docs.curseforge.com/#tocS_SortableGameVersion>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SortableGameVersion {
@@ -136,10 +525,11 @@ pub struct SortableGameVersion {
 
 /// <https://docs.curseforge.com/#tocS_FileDependency>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct FileDependency {
-    #[serde(rename = "modId")]
+    #[serde(rename = "modId", deserialize_with = "deserialize_id")]
     pub project_id: i32,
     pub relation_type: FileRelationType,
     #[cfg(feature = "allow-unknown-fields")]
@@ -149,7 +539,9 @@ pub struct FileDependency {
 
 /// <https://docs.curseforge.com/#tocS_FileRelationType>
 #[derive(Clone, Debug, PartialEq, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum FileRelationType {
     EmbeddedLibrary = 1,
     OptionalDependency = 2,
@@ -164,12 +556,59 @@ pub enum FileRelationType {
 
 /// <https://docs.curseforge.com/#tocS_FileModule>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct FileModule {
     name: String,
+    /// An unsigned 32-bit value, which may also be received as a string.
+    #[serde(deserialize_with = "deserialize_fingerprint")]
     fingerprint: i64,
     #[cfg(feature = "allow-unknown-fields")]
     #[serde(flatten)]
     pub other_fields: serde_json::Value,
 }
+
+/// <https://docs.curseforge.com/#tocS_FingerprintsMatchesResult>
+///
+/// The fingerprints are unsigned 32-bit values, see
+/// [`ProjectFile::file_fingerprint`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct FingerprintsMatchesResult {
+    pub is_cache_built: bool,
+    pub exact_matches: Vec<FingerprintMatch>,
+    pub exact_fingerprints: Vec<i64>,
+    pub partial_matches: Vec<FingerprintMatch>,
+    /// The fingerprints of the modules of each partially matched file, keyed
+    /// by the fingerprint that was sent.
+    #[serde(default)]
+    pub partial_match_fingerprints: HashMap<String, Vec<i64>>,
+    #[serde(default, deserialize_with = "deserialize_vec_or_empty_object")]
+    pub installed_fingerprints: Vec<i64>,
+    /// The fingerprints that did not match any file. This is sent as `null`
+    /// when there are none, which is treated as an empty list.
+    #[serde(default, deserialize_with = "deserialize_vec_or_empty_object")]
+    pub unmatched_fingerprints: Vec<i64>,
+    #[cfg(feature = "allow-unknown-fields")]
+    #[serde(flatten)]
+    pub other_fields: serde_json::Value,
+}
+
+/// <https://docs.curseforge.com/#tocS_FingerprintMatch>
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct FingerprintMatch {
+    /// The ID of the project that the matched file belongs to.
+    #[serde(deserialize_with = "deserialize_id")]
+    pub id: i32,
+    pub file: ProjectFile,
+    pub latest_files: Vec<ProjectFile>,
+    #[cfg(feature = "allow-unknown-fields")]
+    #[serde(flatten)]
+    pub other_fields: serde_json::Value,
+}
@@ -6,6 +6,7 @@ use super::fixes::deserialize_nullable_string;
 
 /// <https://docs.curseforge.com/#tocS_Game>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Game {
@@ -21,8 +22,31 @@ pub struct Game {
     pub other_fields: serde_json::Value,
 }
 
+impl Game {
+    /// Returns `true` if the game's projects can be requested with any API
+    /// key, which is the case when its `api_status` is
+    /// [`CoreApiStatus::Public`]. The projects of a private game can only be
+    /// requested with a key that has been granted access to it.
+    pub fn api_public(&self) -> bool {
+        self.api_status == CoreApiStatus::Public
+    }
+
+    /// Returns `true` if the game has been released on CurseForge, which is
+    /// the case when its `status` is [`CoreStatus::Live`].
+    pub fn is_live(&self) -> bool {
+        self.status == CoreStatus::Live
+    }
+
+    /// Get the URL of an image to display for the game, see
+    /// [`GameAssets::best_icon`].
+    pub fn display_icon(&self) -> Option<&str> {
+        self.assets.best_icon()
+    }
+}
+
 /// <https://docs.curseforge.com/#tocS_GameAssets>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GameAssets {
@@ -37,8 +61,21 @@ pub struct GameAssets {
     pub other_fields: serde_json::Value,
 }
 
+impl GameAssets {
+    /// Get the URL of the first asset that the game has, trying the icon, then
+    /// the tile, and then the cover. This is `None` if the game has none of
+    /// them.
+    pub fn best_icon(&self) -> Option<&str> {
+        self.icon_url
+            .as_deref()
+            .or(self.tile_url.as_deref())
+            .or(self.cover_url.as_deref())
+    }
+}
+
 /// <https://docs.curseforge.com/#tocS_GameVersionsByType>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GameVersions {
@@ -51,6 +88,7 @@ pub struct GameVersions {
 
 /// <https://docs.curseforge.com/#tocS_GameVersionType>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GameVersionType {
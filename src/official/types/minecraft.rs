@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use super::fixes::deserialize_nullable_string;
+use super::projects::ModLoaderType;
+
+/// <https://docs.curseforge.com/#tocS_MinecraftGameVersion>
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct MinecraftGameVersion {
+    pub id: i32,
+    pub game_version_id: i32,
+    /// The name of the version, such as `1.20.1`.
+    pub version_string: String,
+    #[serde(deserialize_with = "deserialize_nullable_string")]
+    pub jar_download_url: Option<String>,
+    #[serde(deserialize_with = "deserialize_nullable_string")]
+    pub json_download_url: Option<String>,
+    pub approved: bool,
+    pub date_modified: DateTime<Utc>,
+    pub game_version_type_id: i32,
+    pub game_version_status: GameVersionStatus,
+    pub game_version_type_status: GameVersionTypeStatus,
+    #[cfg(feature = "allow-unknown-fields")]
+    #[serde(flatten)]
+    pub other_fields: serde_json::Value,
+}
+
+/// <https://docs.curseforge.com/#tocS_MinecraftModLoaderIndex>
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct MinecraftModLoaderIndex {
+    /// The name of the loader and its version, such as `forge-47.2.0`, which
+    /// is passed to [`e::minecraft_modloader`].
+    ///
+    /// [`e::minecraft_modloader`]: crate::official::endpoints::minecraft_modloader
+    pub name: String,
+    pub game_version: String,
+    pub latest: bool,
+    pub recommended: bool,
+    pub date_modified: DateTime<Utc>,
+    #[serde(rename = "type")]
+    pub loader_type: ModLoaderType,
+    #[cfg(feature = "allow-unknown-fields")]
+    #[serde(flatten)]
+    pub other_fields: serde_json::Value,
+}
+
+/// <https://docs.curseforge.com/#tocS_MinecraftModLoaderVersion>
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct MinecraftModLoaderVersion {
+    pub id: i32,
+    pub game_version_id: i32,
+    pub minecraft_game_version_id: i32,
+    pub forge_version: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub loader_type: ModLoaderType,
+    #[serde(deserialize_with = "deserialize_nullable_string")]
+    pub download_url: Option<String>,
+    pub filename: String,
+    pub install_method: ModLoaderInstallMethod,
+    pub latest: bool,
+    pub recommended: bool,
+    pub approved: bool,
+    pub date_modified: DateTime<Utc>,
+    pub maven_version_string: String,
+    /// The version manifest of the loader, as a string of JSON.
+    #[serde(deserialize_with = "deserialize_nullable_string")]
+    pub version_json: Option<String>,
+    #[serde(deserialize_with = "deserialize_nullable_string")]
+    pub libraries_install_location: Option<String>,
+    pub minecraft_version: String,
+    #[serde(deserialize_with = "deserialize_nullable_string")]
+    pub additional_files_json: Option<String>,
+    pub mod_loader_game_version_id: i32,
+    pub mod_loader_game_version_type_id: i32,
+    pub mod_loader_game_version_status: GameVersionStatus,
+    pub mod_loader_game_version_type_status: GameVersionTypeStatus,
+    pub mc_game_version_id: i32,
+    pub mc_game_version_type_id: i32,
+    pub mc_game_version_status: GameVersionStatus,
+    pub mc_game_version_type_status: GameVersionTypeStatus,
+    /// The install profile of the loader, as a string of JSON.
+    #[serde(deserialize_with = "deserialize_nullable_string")]
+    pub install_profile_json: Option<String>,
+    #[cfg(feature = "allow-unknown-fields")]
+    #[serde(flatten)]
+    pub other_fields: serde_json::Value,
+}
+
+/// <https://docs.curseforge.com/#tocS_GameVersionStatus>
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum GameVersionStatus {
+    Approved = 1,
+    Deleted = 2,
+    New = 3,
+    #[cfg(feature = "allow-unknown-fields")]
+    #[serde(other)]
+    Unknown = u8::MAX,
+}
+
+/// <https://docs.curseforge.com/#tocS_GameVersionTypeStatus>
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum GameVersionTypeStatus {
+    Normal = 1,
+    Deleted = 2,
+    #[cfg(feature = "allow-unknown-fields")]
+    #[serde(other)]
+    Unknown = u8::MAX,
+}
+
+/// <https://docs.curseforge.com/#tocS_ModLoaderInstallMethod>
+#[derive(Clone, Debug, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum ModLoaderInstallMethod {
+    ForgeInstaller = 1,
+    ForgeJarInstall = 2,
+    ForgeInstallerV2 = 3,
+    #[cfg(feature = "allow-unknown-fields")]
+    #[serde(other)]
+    Unknown = u8::MAX,
+}
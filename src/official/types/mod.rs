@@ -24,17 +24,25 @@
 //!   ignore any unknown fields returned from the remote API, and just
 //!   deserialize what is expected. Deserializing enumeration variants will
 //!   still fail if the API returns something unexpected.
+//!
+//! Without either feature, the unknown fields can still be discovered at
+//! runtime, by enabling [`ClientOptions::log_unknown_fields`], which logs the
+//! path to each one.
+//!
+//! [`ClientOptions::log_unknown_fields`]: crate::official::client::ClientOptions::log_unknown_fields
 
 pub(crate) mod categories;
 pub(crate) mod core;
 pub(crate) mod files;
 pub(crate) mod games;
+pub(crate) mod minecraft;
 pub(crate) mod projects;
 
 pub use self::categories::*;
 pub use self::core::*;
 pub use self::files::*;
 pub use self::games::*;
+pub use self::minecraft::*;
 pub use self::projects::*;
 
 pub(crate) mod fixes {
@@ -58,8 +66,8 @@ pub(crate) mod fixes {
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 formatter.write_str(
-                    "a string encoding a datetime, with '0001-01-01T00:00:00' treated as a null \
-                     value",
+                    "a string encoding a datetime or a date, with '0001-01-01T00:00:00' treated \
+                     as a null value",
                 )
             }
 
@@ -67,14 +75,223 @@ pub(crate) mod fixes {
             where
                 E: serde::de::Error,
             {
-                if string == "0001-01-01T00:00:00" {
-                    Ok(None)
-                } else {
-                    Ok(Some(string.parse().map_err(E::custom)?))
+                if string == "0001-01-01T00:00:00" || string == "0001-01-01" {
+                    return Ok(None);
+                }
+
+                match string.parse() {
+                    Ok(datetime) => Ok(Some(datetime)),
+                    // Some records only have a date, which is taken as midnight UTC.
+                    Err(error) => match chrono::NaiveDate::parse_from_str(string, "%Y-%m-%d") {
+                        Ok(date) => Ok(Some(date.and_time(chrono::NaiveTime::MIN).and_utc())),
+                        Err(_) => Err(E::custom(error)),
+                    },
                 }
             }
         }
 
         deser.deserialize_str(NullableDateTimeVisitor)
     }
+
+    pub fn deserialize_vec_or_empty_object<'de, D, T>(deser: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        struct VecOrEmptyObjectVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for VecOrEmptyObjectVisitor<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = Vec<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("an array, with an empty object or null treated as empty")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+                while let Some(item) = seq.next_element()? {
+                    items.push(item);
+                }
+
+                Ok(items)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                match map.next_key::<serde::de::IgnoredAny>()? {
+                    None => Ok(Vec::new()),
+                    Some(_) => Err(serde::de::Error::invalid_type(
+                        serde::de::Unexpected::Map,
+                        &self,
+                    )),
+                }
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Vec::new())
+            }
+
+            fn visit_none<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Vec::new())
+            }
+        }
+
+        deser.deserialize_any(VecOrEmptyObjectVisitor(std::marker::PhantomData))
+    }
+
+    /// Deserializes the ID of a project or a file. IDs are assigned in
+    /// increasing order, and one that no longer fits in an `i32` is rejected
+    /// with an error that says so, rather than the generic error for an
+    /// integer out of range.
+    pub fn deserialize_id<'de, D: Deserializer<'de>>(deser: D) -> Result<i32, D::Error> {
+        struct IdVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for IdVisitor {
+            type Value = i32;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("an ID as an integer")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(value).map_err(|_| out_of_range(value.into()))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(value).map_err(|_| out_of_range(value.into()))
+            }
+        }
+
+        // Takes an `i128` so that both visitors can pass their value, and the
+        // bound that was exceeded is known from its sign.
+        fn out_of_range<E: serde::de::Error>(value: i128) -> E {
+            let (side, bound) = if value < 0 {
+                ("starts", i32::MIN)
+            } else {
+                ("ends", i32::MAX)
+            };
+
+            E::custom(format_args!(
+                "the ID {} is outside of the range that this version of the crate supports, which \
+                 {} at {}",
+                value, side, bound,
+            ))
+        }
+
+        deser.deserialize_i64(IdVisitor)
+    }
+
+    /// Like [`deserialize_id`], for an ID that may be `null`.
+    pub fn deserialize_optional_id<'de, D: Deserializer<'de>>(
+        deser: D,
+    ) -> Result<Option<i32>, D::Error> {
+        #[derive(Deserialize)]
+        struct Id(#[serde(deserialize_with = "deserialize_id")] i32);
+
+        Ok(Option::<Id>::deserialize(deser)?.map(|Id(id)| id))
+    }
+
+    /// Deserializes a count that may also be received as a string, which some
+    /// proxies send because the value may be too large for JavaScript.
+    pub fn deserialize_count<'de, D, T>(deser: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<i128, Error = std::num::TryFromIntError>,
+    {
+        struct CountVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for CountVisitor {
+            type Value = i128;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("an integer, or a string encoding one")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(value.into())
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(value.into())
+            }
+
+            fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                string.trim().parse().map_err(E::custom)
+            }
+        }
+
+        T::try_from(deser.deserialize_any(CountVisitor)?).map_err(serde::de::Error::custom)
+    }
+
+    pub fn deserialize_fingerprint<'de, D: Deserializer<'de>>(deser: D) -> Result<i64, D::Error> {
+        struct FingerprintVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for FingerprintVisitor {
+            type Value = i64;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str(
+                    "a fingerprint as a signed or unsigned integer, or a string encoding one",
+                )
+            }
+
+            // A negative value is the same 32 bits read as a signed integer,
+            // and is converted back to the unsigned value.
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match i32::try_from(value) {
+                    Ok(value) if value < 0 => Ok(value as u32 as i64),
+                    _ => self.visit_u64(u64::try_from(value).map_err(E::custom)?),
+                }
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                u32::try_from(value).map(i64::from).map_err(E::custom)
+            }
+
+            fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_i64(string.trim().parse().map_err(E::custom)?)
+            }
+        }
+
+        deser.deserialize_any(FingerprintVisitor)
+    }
 }
@@ -1,14 +1,18 @@
+use std::collections::HashSet;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use super::categories::Category;
 use super::files::{FileIndex, ProjectFile};
-use super::fixes::deserialize_nullable_string;
+use super::fixes::{deserialize_id, deserialize_nullable_string};
 
 /// <https://docs.curseforge.com/#tocS_ModLoaderType>
-#[derive(Clone, Debug, PartialEq, Serialize_repr, Deserialize_repr)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum ModLoaderType {
     Any = 0,
     Forge = 1,
@@ -22,11 +26,73 @@ pub enum ModLoaderType {
     Unknown = u8::MAX,
 }
 
+impl ModLoaderType {
+    /// Parses the name of a mod loader as it appears in the game version
+    /// strings of a file, such as `"Forge"` or `"NeoForge"`. Matching is case
+    /// insensitive, and `None` is returned for anything that is not a loader.
+    pub fn from_game_version_tag(tag: &str) -> Option<Self> {
+        match tag.to_ascii_lowercase().as_str() {
+            "forge" => Some(Self::Forge),
+            "cauldron" => Some(Self::Cauldron),
+            "liteloader" => Some(Self::LiteLoader),
+            "fabric" => Some(Self::Fabric),
+            "quilt" => Some(Self::Quilt),
+            "neoforge" => Some(Self::NeoForge),
+            _ => None,
+        }
+    }
+}
+
+/// The ID of Minecraft, which is the only game whose files list mod loaders
+/// among their game versions.
+const MINECRAFT_GAME_ID: i32 = 432;
+
+/// An entry of [`ProjectFile::game_versions`], classified according to the
+/// game that the file is for. See [`ProjectFile::game_version_tags`].
+///
+/// Only Minecraft files list their mod loaders there. Other games use the same
+/// field for other things, such as the release channels `"Retail"` and
+/// `"Classic"` of World of Warcraft, so their tags are never read as loaders.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GameVersionTag {
+    /// The name of a mod loader, see [`ModLoaderType::from_game_version_tag`].
+    Loader(ModLoaderType),
+    /// Any other tag, such as a game version, an environment, or a release
+    /// channel, exactly as the API sent it.
+    Other(String),
+}
+
+impl GameVersionTag {
+    /// Classifies a tag from a file of the game with the ID `game_id`.
+    pub fn classify(game_id: i32, tag: &str) -> Self {
+        let loader = match game_id {
+            MINECRAFT_GAME_ID => ModLoaderType::from_game_version_tag(tag),
+            _ => None,
+        };
+
+        match loader {
+            Some(loader) => Self::Loader(loader),
+            None => Self::Other(tag.to_owned()),
+        }
+    }
+
+    /// Get the mod loader, if this tag is one.
+    pub fn loader(&self) -> Option<&ModLoaderType> {
+        match self {
+            Self::Loader(loader) => Some(loader),
+            Self::Other(_) => None,
+        }
+    }
+}
+
 /// <https://docs.curseforge.com/#tocS_Mod>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Project {
+    #[serde(deserialize_with = "deserialize_id")]
     pub id: i32,
     pub game_id: i32,
     pub name: String,
@@ -42,6 +108,7 @@ pub struct Project {
     pub authors: Vec<ProjectAuthor>,
     pub logo: Option<ProjectAsset>,
     pub screenshots: Vec<ProjectAsset>,
+    #[serde(deserialize_with = "deserialize_id")]
     pub main_file_id: i32,
     pub latest_files: Vec<ProjectFile>,
     pub latest_files_indexes: Vec<FileIndex>,
@@ -57,8 +124,147 @@ pub struct Project {
     pub other_fields: serde_json::Value,
 }
 
+impl Project {
+    /// Rolls up the mod loaders supported by any of the project's latest files.
+    ///
+    /// Loaders are collected from the `mod_loader` of every entry in
+    /// `latest_files_indexes`, and from the loader names that appear in the
+    /// `game_versions` and `sortable_game_versions` of every entry in
+    /// `latest_files`, because some files only list their loader as a string.
+    /// The names are only read as loaders for games that have them, see
+    /// [`GameVersionTag`]. [`ModLoaderType::Any`] is never included.
+    pub fn supported_loaders(&self) -> HashSet<ModLoaderType> {
+        let from_indexes = self
+            .latest_files_indexes
+            .iter()
+            .filter_map(|index| index.mod_loader.clone());
+        let from_files = self.latest_files.iter().flat_map(|file| {
+            file.game_version_tags()
+                .into_iter()
+                .filter_map(|tag| tag.loader().cloned())
+        });
+
+        from_indexes
+            .chain(from_files)
+            .filter(|loader| *loader != ModLoaderType::Any)
+            .collect()
+    }
+
+    /// Get the entry of `latest_files` with the newest `file_date`, regardless
+    /// of the game versions it supports. Files with the same date are ordered
+    /// by ID, the higher being newer. This may differ from `main_file_id`,
+    /// which is chosen by the project's authors.
+    pub fn newest_file(&self) -> Option<&ProjectFile> {
+        self.latest_files
+            .iter()
+            .max_by_key(|file| (file.file_date, file.id))
+    }
+
+    /// Get the entry of `categories` that is the project's class (such as
+    /// *Mods* or *Modpacks*), if the API included one.
+    pub fn primary_class(&self) -> Option<&Category> {
+        self.categories
+            .iter()
+            .find(|category| category.is_class == Some(true))
+    }
+
+    /// Returns `true` if the project is a modpack, which is determined by the
+    /// slug of its class, or by its `class_id` for the games that are known.
+    /// A modpack's files can be read with
+    #[cfg_attr(
+        feature = "modpack",
+        doc = "[`Client::modpack_contents`](crate::official::client::Client::modpack_contents),"
+    )]
+    // Without the feature, the method does not exist to be linked to.
+    #[cfg_attr(not(feature = "modpack"), doc = "`Client::modpack_contents`,")]
+    /// with the `modpack` feature.
+    pub fn is_modpack(&self) -> bool {
+        /// The class of modpacks for Minecraft.
+        const MINECRAFT_MODPACKS: i32 = 4471;
+
+        self.class_id == Some(MINECRAFT_MODPACKS)
+            || self
+                .primary_class()
+                .is_some_and(|class| class.slug.as_deref() == Some("modpacks"))
+    }
+
+    /// Get every entry of `categories` that is not a class, these are the tags
+    /// typically displayed alongside the project.
+    pub fn leaf_categories(&self) -> Vec<&Category> {
+        self.categories
+            .iter()
+            .filter(|category| category.is_class != Some(true))
+            .collect()
+    }
+}
+
+/// A trimmed version of [`Project`] that only retains the identifying fields
+/// and a few commonly displayed ones, dropping heavy members such as
+/// `screenshots` and `latest_files`.
+///
+/// This is yielded by [`Client::search_projects_lite`] for indexers that only
+/// care about a subset of each project and want to keep memory usage low. It
+/// can also be serialized, as a compact representation of a project for caches
+/// or for sending to another process.
+///
+/// [`Client::search_projects_lite`]: crate::official::client::Client::search_projects_lite
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectSummary {
+    pub id: i32,
+    pub game_id: i32,
+    pub class_id: Option<i32>,
+    pub name: String,
+    pub slug: String,
+    pub summary: String,
+    pub download_count: f64,
+    pub primary_category_id: u32,
+    pub main_file_id: i32,
+    pub date_modified: DateTime<Utc>,
+    /// The `url` of the project's `logo`, if it has one.
+    pub logo_url: Option<String>,
+}
+
+impl From<Project> for ProjectSummary {
+    fn from(other: Project) -> Self {
+        Self {
+            id: other.id,
+            game_id: other.game_id,
+            class_id: other.class_id,
+            name: other.name,
+            slug: other.slug,
+            summary: other.summary,
+            download_count: other.download_count,
+            primary_category_id: other.primary_category_id,
+            main_file_id: other.main_file_id,
+            date_modified: other.date_modified,
+            logo_url: other.logo.and_then(|logo| logo.url),
+        }
+    }
+}
+
+impl From<&Project> for ProjectSummary {
+    fn from(other: &Project) -> Self {
+        Self {
+            id: other.id,
+            game_id: other.game_id,
+            class_id: other.class_id,
+            name: other.name.clone(),
+            slug: other.slug.clone(),
+            summary: other.summary.clone(),
+            download_count: other.download_count,
+            primary_category_id: other.primary_category_id,
+            main_file_id: other.main_file_id,
+            date_modified: other.date_modified,
+            logo_url: other.logo.as_ref().and_then(|logo| logo.url.clone()),
+        }
+    }
+}
+
 /// <https://docs.curseforge.com/#tocS_ModLinks>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ProjectLinks {
@@ -76,7 +282,9 @@ pub struct ProjectLinks {
 
 /// <https://docs.curseforge.com/#tocS_ModLinks>
 #[derive(Clone, Debug, PartialEq, Serialize_repr, Deserialize_repr)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum ProjectStatus {
     New = 1,
     ChangesRequired = 2,
@@ -95,6 +303,7 @@ pub enum ProjectStatus {
 
 /// <https://docs.curseforge.com/#tocS_ModAuthor>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ProjectAuthor {
@@ -108,17 +317,20 @@ pub struct ProjectAuthor {
 
 /// <https://docs.curseforge.com/#tocS_ModAsset>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ProjectAsset {
     pub id: i32,
-    #[serde(rename = "modId")]
+    #[serde(rename = "modId", deserialize_with = "deserialize_id")]
     pub project_id: i32,
     pub title: String,
     #[serde(deserialize_with = "deserialize_nullable_string")]
     pub description: Option<String>,
-    pub thumbnail_url: String,
-    pub url: String,
+    #[serde(deserialize_with = "deserialize_nullable_string")]
+    pub thumbnail_url: Option<String>,
+    #[serde(deserialize_with = "deserialize_nullable_string")]
+    pub url: Option<String>,
     #[cfg(feature = "allow-unknown-fields")]
     #[serde(flatten)]
     pub other_fields: serde_json::Value,
@@ -126,6 +338,7 @@ pub struct ProjectAsset {
 
 /// <https://docs.curseforge.com/#tocS_FeaturedModsResponse>
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct FeaturedProjects {
@@ -0,0 +1,31 @@
+//! Contains the interceptor installed by [`ClientOptions::log_unknown_fields`].
+//!
+//! [`ClientOptions::log_unknown_fields`]: crate::official::client::ClientOptions::log_unknown_fields
+
+use isahc::http::Request;
+use isahc::interceptor::{Context, Interceptor, InterceptorFuture};
+use isahc::AsyncBody;
+
+/// Marks every response with this same type as an extension, which tells the
+/// endpoint that made the request to log the fields of the body that it did
+/// not deserialize. The interceptor itself does not read the body.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LogUnknownFields;
+
+impl Interceptor for LogUnknownFields {
+    type Err = isahc::Error;
+
+    fn intercept<'a>(
+        &'a self,
+        request: Request<AsyncBody>,
+        ctx: Context<'a>,
+    ) -> InterceptorFuture<'a, Self::Err> {
+        Box::pin(async move {
+            let mut response = ctx.send(request).await?;
+
+            response.extensions_mut().insert(LogUnknownFields);
+
+            Ok(response)
+        })
+    }
+}
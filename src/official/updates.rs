@@ -0,0 +1,253 @@
+//! Contains [`Client`] methods that check installed project files for newer
+//! versions.
+//!
+//! Like the methods of the [`batch`](crate::official::batch) module, each of
+//! these has an `_until` variant that takes an optional deadline for the whole
+//! batch.
+
+use std::collections::HashMap;
+use std::pin::pin;
+use std::time::Duration;
+
+use futures_util::future::{self, Either};
+
+use crate::official::batch::{bounded_map_until, Concurrency};
+use crate::official::client::Client;
+use crate::official::types::{FileIndex, ModLoaderType, Project, ProjectFile};
+use crate::Error;
+
+/// An installed file, bundled with the newest file that could replace it and
+/// that file's changelog. See [`Client::update_candidates`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpdateCandidate {
+    pub project_id: i32,
+    pub current_file_id: i32,
+    /// This is `None` if there is no newer compatible file.
+    pub new_file: Option<ProjectFile>,
+    /// This is `None` if and only if `new_file` is `None`.
+    pub changelog: Option<String>,
+}
+
+/// The result of checking a single installed file for updates, see
+/// [`Client::check_updates`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ModUpdateStatus {
+    /// The installed file is the newest compatible file.
+    UpToDate,
+    /// There is a newer compatible file than the one installed.
+    UpdateAvailable(ProjectFile),
+    /// The project has no file that matches the game version and loader.
+    NoCompatibleFile,
+    /// The requests for this project failed.
+    Error(Error),
+}
+
+/// Finds the newest entry of [`Project::latest_files_indexes`] that matches
+/// the game version and mod loader, when those are provided. Newer files are
+/// assumed to have higher IDs, because the index does not carry a date.
+fn newest_compatible_index<'p>(
+    project: &'p Project,
+    game_version: Option<&str>,
+    loader: Option<&ModLoaderType>,
+) -> Option<&'p FileIndex> {
+    let loader = loader.filter(|loader| **loader != ModLoaderType::Any);
+
+    project
+        .latest_files_indexes
+        .iter()
+        .filter(|index| game_version.is_none_or(|version| index.game_version == version))
+        .filter(|index| loader.is_none_or(|loader| index.mod_loader.as_ref() == Some(loader)))
+        .max_by_key(|index| index.file_id)
+}
+
+impl Client {
+    /// Given pairs of `(project_id, file_id)` for installed files, checks each
+    /// for a newer file compatible with `game_version` and `loader`.
+    ///
+    /// Each project is requested separately, with at most `concurrency`
+    /// requests in flight, so that a failure for one project is reported in
+    /// its own [`ModUpdateStatus::Error`] without affecting the others. The
+    /// statuses are returned in the same order as `installed`.
+    pub async fn check_updates<C>(
+        &self,
+        installed: &[(i32, i32)],
+        game_version: Option<&str>,
+        loader: Option<ModLoaderType>,
+        concurrency: C,
+    ) -> Vec<ModUpdateStatus>
+    where
+        C: Into<Concurrency>,
+    {
+        self.check_updates_until(installed, game_version, loader, concurrency, None)
+            .await
+    }
+
+    /// Like [`Self::check_updates`], with a `deadline` for the whole batch.
+    /// Every installed file that was not checked once it has elapsed is
+    /// reported with [`Error::DeadlineElapsed`].
+    pub async fn check_updates_until<C>(
+        &self,
+        installed: &[(i32, i32)],
+        game_version: Option<&str>,
+        loader: Option<ModLoaderType>,
+        concurrency: C,
+        deadline: Option<Duration>,
+    ) -> Vec<ModUpdateStatus>
+    where
+        C: Into<Concurrency>,
+    {
+        let loader = loader.as_ref();
+
+        bounded_map_until(
+            installed.iter().copied(),
+            self.concurrency_limit(concurrency.into()),
+            deadline,
+            |(project_id, file_id)| async move {
+                let project = match self.project(project_id).await {
+                    Ok(project) => project,
+                    Err(error) => return ModUpdateStatus::Error(error),
+                };
+
+                let new_file_id = match newest_compatible_index(&project, game_version, loader) {
+                    Some(index) if index.file_id > file_id => index.file_id,
+                    Some(_) => return ModUpdateStatus::UpToDate,
+                    None => return ModUpdateStatus::NoCompatibleFile,
+                };
+
+                let latest = project
+                    .latest_files
+                    .into_iter()
+                    .find(|file| file.id == new_file_id);
+
+                match latest {
+                    Some(file) => ModUpdateStatus::UpdateAvailable(file),
+                    None => match self.project_file(project_id, new_file_id).await {
+                        Ok(file) => ModUpdateStatus::UpdateAvailable(file),
+                        Err(error) => ModUpdateStatus::Error(error),
+                    },
+                }
+            },
+        )
+        .await
+        .into_iter()
+        .map(|status| status.unwrap_or(ModUpdateStatus::Error(Error::DeadlineElapsed)))
+        .collect()
+    }
+
+    /// Given pairs of `(project_id, file_id)` for installed files, finds the
+    /// newest file of each project compatible with `game_version` and
+    /// `loader`, and fetches its changelog.
+    ///
+    /// The projects and the new files are each retrieved with a single batched
+    /// request, and the changelogs are then requested with at most
+    /// `concurrency` requests in flight. The candidates are returned in the
+    /// same order as `installed`.
+    pub async fn update_candidates<C>(
+        &self,
+        installed: &[(i32, i32)],
+        game_version: Option<&str>,
+        loader: Option<ModLoaderType>,
+        concurrency: C,
+    ) -> Result<Vec<UpdateCandidate>, Error>
+    where
+        C: Into<Concurrency>,
+    {
+        self.update_candidates_until(installed, game_version, loader, concurrency, None)
+            .await
+    }
+
+    /// Like [`Self::update_candidates`], with a `deadline` for the whole
+    /// batch. The candidates are only returned together, so if it elapses
+    /// before every request has finished, [`Error::DeadlineElapsed`] is
+    /// returned instead.
+    pub async fn update_candidates_until<C>(
+        &self,
+        installed: &[(i32, i32)],
+        game_version: Option<&str>,
+        loader: Option<ModLoaderType>,
+        concurrency: C,
+        deadline: Option<Duration>,
+    ) -> Result<Vec<UpdateCandidate>, Error>
+    where
+        C: Into<Concurrency>,
+    {
+        let candidates =
+            pin!(self.update_candidates_inner(installed, game_version, loader, concurrency.into()));
+
+        match deadline {
+            Some(deadline) => {
+                match future::select(candidates, futures_timer::Delay::new(deadline)).await {
+                    Either::Left((candidates, _)) => candidates,
+                    Either::Right(_) => Err(Error::DeadlineElapsed),
+                }
+            }
+            None => candidates.await,
+        }
+    }
+
+    async fn update_candidates_inner(
+        &self,
+        installed: &[(i32, i32)],
+        game_version: Option<&str>,
+        loader: Option<ModLoaderType>,
+        concurrency: Concurrency,
+    ) -> Result<Vec<UpdateCandidate>, Error> {
+        if installed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let projects = self
+            .projects(installed.iter().map(|(project_id, _)| *project_id))
+            .await?
+            .into_iter()
+            .map(|project| (project.id, project))
+            .collect::<HashMap<_, _>>();
+
+        let new_file_ids = installed
+            .iter()
+            .map(|(project_id, file_id)| {
+                projects
+                    .get(project_id)
+                    .and_then(|project| {
+                        newest_compatible_index(project, game_version, loader.as_ref())
+                    })
+                    .map(|index| index.file_id)
+                    .filter(|new_file_id| new_file_id > file_id)
+            })
+            .collect::<Vec<_>>();
+
+        let files = if new_file_ids.iter().any(Option::is_some) {
+            self.project_files_by_ids(new_file_ids.iter().flatten().copied())
+                .await?
+                .into_iter()
+                .map(|file| (file.id, file))
+                .collect::<HashMap<_, _>>()
+        } else {
+            HashMap::new()
+        };
+        let files = &files;
+
+        self.bounded_map(
+            installed.iter().zip(new_file_ids),
+            concurrency,
+            |(&(project_id, current_file_id), new_file_id)| async move {
+                let new_file = new_file_id.and_then(|file_id| files.get(&file_id).cloned());
+                let changelog = match &new_file {
+                    Some(file) => Some(self.project_file_changelog(project_id, file.id).await?),
+                    None => None,
+                };
+
+                Ok(UpdateCandidate {
+                    project_id,
+                    current_file_id,
+                    new_file,
+                    changelog,
+                })
+            },
+        )
+        .await
+        .into_iter()
+        .collect()
+    }
+}
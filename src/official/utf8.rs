@@ -0,0 +1,55 @@
+//! Contains the interceptor installed by [`ClientOptions::lossy_utf8`].
+//!
+//! [`ClientOptions::lossy_utf8`]: crate::official::client::ClientOptions::lossy_utf8
+
+use isahc::http::header::CONTENT_LENGTH;
+use isahc::http::{HeaderValue, Request, Response};
+use isahc::interceptor::{Context, Interceptor, InterceptorFuture};
+use isahc::AsyncBody;
+
+/// Replaces every sequence of bytes in a response body that is not valid
+/// UTF-8 with `U+FFFD REPLACEMENT CHARACTER`, before handing the response back
+/// to the endpoint that made the request. Such bytes can only be valid JSON
+/// inside of a string, so this turns an error for the whole response into a
+/// replacement character in a single value, such as a description. Bodies
+/// that are already valid are passed through unchanged.
+///
+/// Each replacement character is longer than the bytes that it replaces, so
+/// the `content-length` of a body that was changed is set to its new length.
+#[derive(Debug)]
+pub(crate) struct LossyUtf8;
+
+impl Interceptor for LossyUtf8 {
+    type Err = isahc::Error;
+
+    fn intercept<'a>(
+        &'a self,
+        request: Request<AsyncBody>,
+        ctx: Context<'a>,
+    ) -> InterceptorFuture<'a, Self::Err> {
+        Box::pin(async move {
+            use futures_lite::io::AsyncReadExt;
+
+            let (mut parts, mut body) = ctx.send(request).await?.into_parts();
+            let mut bytes = Vec::new();
+
+            body.read_to_end(&mut bytes).await?;
+
+            let bytes = match String::from_utf8(bytes) {
+                Ok(string) => string.into_bytes(),
+                Err(error) => {
+                    let bytes = String::from_utf8_lossy(error.as_bytes())
+                        .into_owned()
+                        .into_bytes();
+
+                    parts
+                        .headers
+                        .insert(CONTENT_LENGTH, HeaderValue::from(bytes.len()));
+                    bytes
+                }
+            };
+
+            Ok(Response::from_parts(parts, AsyncBody::from(bytes)))
+        })
+    }
+}
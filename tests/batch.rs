@@ -0,0 +1,70 @@
+//! These tests cover the deadline of the batch methods, against a local
+//! listener that accepts connections but never responds.
+
+use std::net::TcpListener;
+use std::time::{Duration, Instant};
+
+use curseforge::official::prelude::*;
+use curseforge::Error;
+
+/// Once the deadline elapses, every batch method returns, and reports each
+/// of its requests as failed with `DeadlineElapsed`.
+#[test]
+fn deadline_elapsed() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}/v1/", listener.local_addr().unwrap());
+    let client = Client::new(base.as_str(), None, None).unwrap();
+    let deadline = Some(Duration::from_millis(200));
+
+    smol::block_on(async {
+        let started = Instant::now();
+        let games = client.games_by_ids_until([1, 2], 2, deadline).await;
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(
+            games
+                .iter()
+                .map(|(game_id, _)| *game_id)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert!(games
+            .iter()
+            .all(|(_, result)| matches!(result, Err(Error::DeadlineElapsed))));
+
+        let results = client
+            .projects_chunked_until([1, 2, 3], 2, 2, deadline)
+            .await;
+
+        assert!(results.items.is_empty());
+        assert_eq!(
+            results
+                .errors
+                .iter()
+                .map(|(range, _)| range.clone())
+                .collect::<Vec<_>>(),
+            [0..2, 2..3]
+        );
+        assert!(results
+            .errors
+            .iter()
+            .all(|(_, error)| matches!(error, Error::DeadlineElapsed)));
+
+        let installed = [(1, 1), (2, 2)];
+        let statuses = client
+            .check_updates_until(&installed, None, None, 2, deadline)
+            .await;
+
+        assert_eq!(statuses.len(), 2);
+        assert!(statuses
+            .iter()
+            .all(|status| matches!(status, ModUpdateStatus::Error(Error::DeadlineElapsed))));
+
+        let candidates = client
+            .update_candidates_until(&installed, None, None, 2, deadline)
+            .await;
+
+        assert!(matches!(candidates, Err(Error::DeadlineElapsed)));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    });
+}
@@ -0,0 +1,134 @@
+//! These tests build the URLs of the CFWidget API from project paths as a user
+//! may enter them, and check how the client handles the statuses that CFWidget
+//! responds with, using a local server rather than the API.
+
+#![cfg(feature = "cfwidget")]
+
+use curseforge::cfwidget::endpoints::{normalize_path, project_url, DEFAULT_API_BASE};
+use curseforge::cfwidget::Client;
+use curseforge::Error;
+
+mod common;
+
+/// Slashes, queries, and fragments do not change the path.
+#[test]
+fn normalize_path_cases() {
+    let cases = [
+        ("minecraft/mc-mods/jei", "minecraft/mc-mods/jei"),
+        ("/minecraft/mc-mods/jei/", "minecraft/mc-mods/jei"),
+        ("//minecraft//mc-mods/jei", "minecraft/mc-mods/jei"),
+        (
+            "minecraft/mc-mods/jei?version=1.20.1",
+            "minecraft/mc-mods/jei",
+        ),
+        ("minecraft/mc-mods/jei/#files", "minecraft/mc-mods/jei"),
+        ("238222", "238222"),
+        ("/238222/", "238222"),
+        (
+            "wow/addons/deadly boss mods",
+            "wow/addons/deadly%20boss%20mods",
+        ),
+        (
+            "wow/addons/deadly%20boss%20mods",
+            "wow/addons/deadly%20boss%20mods",
+        ),
+        ("", ""),
+        ("/", ""),
+    ];
+
+    for (path, expected) in cases {
+        assert_eq!(normalize_path(path), expected, "{path:?}");
+    }
+}
+
+/// Equivalent paths result in the same URL, whether the base has a trailing
+/// slash or not.
+#[test]
+fn project_urls() {
+    let base = url::Url::parse(DEFAULT_API_BASE).unwrap();
+    let expected = "https://api.cfwidget.com/minecraft/mc-mods/jei";
+
+    assert_eq!(
+        project_url(&base, "/minecraft/mc-mods/jei/").as_str(),
+        expected
+    );
+    assert_eq!(
+        project_url(&base, "minecraft/mc-mods/jei").as_str(),
+        expected
+    );
+
+    let base = url::Url::parse("https://example.com/cfwidget?key=1").unwrap();
+
+    assert_eq!(
+        project_url(&base, "minecraft/mc-mods/a b").as_str(),
+        "https://example.com/cfwidget/minecraft/mc-mods/a%20b"
+    );
+}
+
+/// Release types convert between the two APIs without changing their level.
+#[cfg(feature = "official")]
+#[test]
+fn release_type_conversions() {
+    use curseforge::cfwidget::types::ReleaseType;
+    use curseforge::official::types::FileReleaseType;
+
+    let cases = [
+        (ReleaseType::Release, FileReleaseType::Release),
+        (ReleaseType::Beta, FileReleaseType::Beta),
+        (ReleaseType::Alpha, FileReleaseType::Alpha),
+    ];
+
+    for (cfwidget, official) in cases {
+        assert_eq!(FileReleaseType::from(cfwidget.clone()), official);
+        assert_eq!(ReleaseType::from(official), cfwidget);
+    }
+
+    #[cfg(feature = "allow-unknown-fields")]
+    {
+        assert_eq!(
+            ReleaseType::from(FileReleaseType::Unknown),
+            ReleaseType::Unknown
+        );
+        assert_eq!(
+            FileReleaseType::from(ReleaseType::Unknown),
+            FileReleaseType::Unknown
+        );
+    }
+}
+
+/// A project that CFWidget has queued, or that does not exist, has its own
+/// error, and the paths are built from the arguments.
+#[test]
+fn client_statuses() {
+    let (base, requests) = common::serve(&[
+        "HTTP/1.1 202 Accepted\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+        "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+        "HTTP/1.1 500 Internal Server Error\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+    ]);
+    let client = Client::new(base).unwrap();
+
+    smol::block_on(async {
+        let error = client.project(238222).await.unwrap_err();
+        assert!(matches!(error, Error::InQueue { .. }));
+        assert!(error.is_transient());
+        assert_eq!(requests.recv().unwrap(), "GET /238222 HTTP/1.1");
+
+        let error = client
+            .project_by_path("minecraft", "mc-mods", "jei")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::NotFound { .. }));
+        assert_eq!(
+            requests.recv().unwrap(),
+            "GET /minecraft/mc-mods/jei HTTP/1.1"
+        );
+
+        let error = client.project(1).await.unwrap_err();
+        assert!(matches!(error, Error::StatusNotOk { status, .. } if status == 500));
+    });
+
+    assert!(matches!(
+        Client::new("data:text/plain,"),
+        Err(Error::BadBaseUrl)
+    ));
+}
@@ -0,0 +1,112 @@
+//! A local server shared by the tests that cover behavior over HTTP, so that
+//! they do not depend on the network.
+
+// Each test crate that includes this module uses only some of it.
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+/// Starts a server that sends each of `responses` in turn, one per
+/// connection, and returns the URL of the server along with each request line
+/// that it received.
+///
+/// Each connection is held open until the client closes it, so a response
+/// that is shorter than its `content-length` stalls partway through the body.
+pub fn serve<I>(responses: I) -> (String, Receiver<String>)
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    serve_with(responses, |mut head| head.swap_remove(0))
+}
+
+/// Like [`serve`], but returns every line of the head of each request, the
+/// request line followed by the headers.
+pub fn serve_heads<I>(responses: I) -> (String, Receiver<Vec<String>>)
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    serve_with(responses, |head| head)
+}
+
+fn serve_with<I, T>(responses: I, map: fn(Vec<String>) -> T) -> (String, Receiver<T>)
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+    T: Send + 'static,
+{
+    let responses = responses
+        .into_iter()
+        .map(|response| response.as_ref().to_vec())
+        .collect::<Vec<_>>();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut head = Vec::new();
+            let mut line = String::new();
+
+            while reader.read_line(&mut line).unwrap() > 2 {
+                head.push(line.trim_end().to_owned());
+                line.clear();
+            }
+
+            // The receiver may have been dropped by a test that does not
+            // check the requests.
+            let _ = sender.send(map(head));
+
+            stream.write_all(&response).unwrap();
+            stream
+                .set_read_timeout(Some(Duration::from_secs(10)))
+                .unwrap();
+            let _ = stream.read(&mut [0; 1]);
+        }
+    });
+
+    (url, receiver)
+}
+
+/// Formats a response with `status` and a JSON `body`, along with the
+/// `content-length` of the body.
+pub fn json(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: \
+         close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )
+}
+
+/// Formats the body of a page of search results, each a copy of the project
+/// fixture with one of `ids`, out of `total` results.
+pub fn search_page(index: usize, ids: &[i32], total: usize) -> String {
+    let mut project: serde_json::Value =
+        serde_json::from_str(include_str!("../fixtures/project.json")).unwrap();
+    let data = ids
+        .iter()
+        .map(|id| {
+            project["id"] = (*id).into();
+            project.clone()
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "data": data,
+        "pagination": {
+            "index": index,
+            "pageSize": 50,
+            "resultCount": ids.len(),
+            "totalCount": total,
+        },
+    })
+    .to_string()
+}
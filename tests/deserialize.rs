@@ -0,0 +1,398 @@
+//! These tests deserialize fixtures of responses that the API (or one of the
+//! community proxies) has returned, and that have been known to cause
+//! problems. No requests are made.
+
+use curseforge::official::prelude::*;
+use curseforge::Error;
+
+/// Some categories are returned without `isClass`, `classId`, or
+/// `parentCategoryId`.
+#[test]
+fn category_without_class() {
+    let fixture = include_str!("fixtures/category_without_class.json");
+    let category: Category = serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(category.is_class, None);
+    assert_eq!(category.class_id, None);
+    assert_eq!(category.parent_category_id, None);
+}
+
+/// Some categories have a `dateModified` with only a date, which is taken as
+/// midnight UTC.
+#[test]
+fn category_date_only() {
+    use chrono::TimeZone;
+
+    let fixture = include_str!("fixtures/category_date_only.json");
+    let category: Category = serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(
+        category.date_modified,
+        Some(chrono::Utc.with_ymd_and_hms(2014, 5, 8, 0, 0, 0).unwrap())
+    );
+
+    let fixture = fixture.replace("2014-05-08", "0001-01-01");
+    let category: Category = serde_json::from_str(&fixture).unwrap();
+    assert_eq!(category.date_modified, None);
+
+    let fixture = fixture.replace("0001-01-01", "08/05/2014");
+    assert!(serde_json::from_str::<Category>(&fixture).is_err());
+}
+
+/// Some proxies rewrite `downloadUrl` to a path relative to the CDN.
+#[test]
+fn file_relative_download_url() {
+    let fixture = include_str!("fixtures/file_relative_download_url.json");
+    let file: ProjectFile = serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(
+        file.resolved_download_url().unwrap().unwrap().as_str(),
+        "https://edge.forgecdn.net/files/4593/548/jei-1.20.1-forge-15.2.0.27.jar"
+    );
+}
+
+/// Fingerprints are unsigned 32-bit values, which may exceed `i32`, and may be
+/// encoded as strings. Negative values are the same bits read as signed, and
+/// anything outside of 32 bits is rejected.
+#[test]
+fn file_large_fingerprints() {
+    let fixture = include_str!("fixtures/file_large_fingerprints.json");
+    let file: ProjectFile = serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(file.file_fingerprint, u32::MAX as i64);
+
+    let modules = serde_json::to_value(&file.modules).unwrap();
+    let fingerprints = modules
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|module| module["fingerprint"].as_i64().unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(fingerprints, [4294967290, 4294967291, 4294967291]);
+
+    for fingerprint in ["4294967296", "-2147483649"] {
+        let fixture = fixture.replace("\"4294967295\"", fingerprint);
+        assert!(serde_json::from_str::<ProjectFile>(&fixture).is_err());
+    }
+}
+
+/// Some proxies send `dependencies` as an empty object, or `null`, instead of
+/// an empty array.
+#[test]
+fn file_dependencies_empty_object() {
+    let fixture = include_str!("fixtures/file_dependencies_empty_object.json");
+    let file: ProjectFile = serde_json::from_str(fixture).unwrap();
+
+    assert!(file.dependencies.is_empty());
+
+    let mut value: serde_json::Value = serde_json::from_str(fixture).unwrap();
+    value["dependencies"] = serde_json::Value::Null;
+    let file: ProjectFile = serde_json::from_value(value.clone()).unwrap();
+
+    assert!(file.dependencies.is_empty());
+
+    value["dependencies"] = serde_json::json!({ "modId": 1, "relationType": 3 });
+
+    assert!(serde_json::from_value::<ProjectFile>(value).is_err());
+}
+
+/// Some proxies send the counts of a page as strings, which are parsed as if
+/// they were numbers, as long as they fit in the field.
+#[test]
+fn pagination_string_counts() {
+    let fixture = include_str!("fixtures/search_string_counts.json");
+    let response: PaginatedDataResponse<Project> = serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(response.pagination.result_count, 0);
+    assert_eq!(response.pagination.total_count, 9007199254740993);
+
+    let mut value: serde_json::Value = serde_json::from_str(fixture).unwrap();
+    value["pagination"]["resultCount"] = serde_json::json!("3000000000");
+    let error = serde_json::from_value::<PaginatedDataResponse<Project>>(value).unwrap_err();
+
+    assert!(error.to_string().contains("out of range"), "{}", error);
+}
+
+/// IDs that no longer fit in an `i32` are rejected with an error that names
+/// the field and the supported range, and IDs that do are unaffected.
+#[test]
+fn file_id_out_of_range() {
+    let fixture = include_str!("fixtures/file_relative_download_url.json");
+    let uri = url::Url::parse("https://api.curseforge.com/v1/mods/238222/files/1").unwrap();
+
+    let mut value: serde_json::Value = serde_json::from_str(fixture).unwrap();
+    value["alternateFileId"] = serde_json::json!(i32::MAX);
+    value["serverPackFileId"] = serde_json::Value::Null;
+    let file: ProjectFile = serde_json::from_value(value.clone()).unwrap();
+
+    assert_eq!(file.alternate_file_id, Some(i32::MAX));
+    assert_eq!(file.server_pack_file_id, None);
+
+    for (field, id, bound) in [
+        (
+            "id",
+            serde_json::json!(3_000_000_000_u64),
+            "ends at 2147483647",
+        ),
+        (
+            "alternateFileId",
+            serde_json::json!(3_000_000_000_u64),
+            "ends at 2147483647",
+        ),
+        (
+            "modId",
+            serde_json::json!(-3_000_000_000_i64),
+            "starts at -2147483648",
+        ),
+    ] {
+        let mut value = value.clone();
+        value[field] = id.clone();
+        let body = serde_json::to_vec(&serde_json::json!({ "data": value })).unwrap();
+
+        match e::decode::<DataResponse<ProjectFile>>(uri.clone(), &body) {
+            Err(Error::Deserialize { error, .. }) => {
+                assert_eq!(error.path().to_string(), format!("data.{}", field));
+                assert!(error
+                    .to_string()
+                    .contains(&format!("the ID {} is outside", id)));
+                assert!(error.to_string().contains(bound));
+            }
+            other => panic!("expected a deserialize error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
+
+/// Some resources that do not exist are returned as `"data": null`.
+#[test]
+fn data_null() {
+    let fixture = include_str!("fixtures/data_null.json");
+    let uri = url::Url::parse("https://api.curseforge.com/v1/mods/1").unwrap();
+
+    let result = e::decode::<DataResponse<Project>>(uri.clone(), fixture.as_bytes());
+    assert!(matches!(result, Err(Error::NotFound { .. })));
+
+    let result = e::decode::<DataResponse<Option<Project>>>(uri.clone(), fixture.as_bytes());
+    assert!(matches!(result, Ok(DataResponse { data: None, .. })));
+
+    let result = e::decode::<DataResponse<Project>>(uri, br#"{"data":{}}"#);
+    assert!(matches!(result, Err(Error::Deserialize { .. })));
+}
+
+/// A field of the wrong type is reported with the URL of the request and the
+/// path to the field, in the message of the error.
+#[test]
+fn wrong_field_type_display() {
+    let fixture = include_str!("fixtures/file_relative_download_url.json");
+    let uri = url::Url::parse("https://api.curseforge.com/v1/mods/238222/files/4593548").unwrap();
+
+    let mut value: serde_json::Value = serde_json::from_str(fixture).unwrap();
+    value["fileLength"] = serde_json::json!("large");
+    let body = serde_json::to_vec(&serde_json::json!({ "data": value })).unwrap();
+
+    let error = e::decode::<DataResponse<ProjectFile>>(uri.clone(), &body).unwrap_err();
+    let message = error.to_string();
+
+    assert!(matches!(error, Error::Deserialize { .. }));
+    assert!(message.contains(uri.as_str()));
+    assert!(message.contains("data.fileLength: invalid type: string \"large\""));
+}
+
+/// Some descriptions are sent in another encoding, which is reported as such
+/// rather than as a failure to deserialize.
+#[test]
+fn description_invalid_utf8() {
+    let uri = url::Url::parse("https://api.curseforge.com/v1/mods/1/description").unwrap();
+    let body = b"{\"data\":\"<p>Caf\xe9</p>\"}";
+
+    match e::decode::<DataResponse<Description>>(uri, body) {
+        Err(Error::InvalidUtf8 { error, bytes, .. }) => {
+            assert_eq!(error.valid_up_to(), 15);
+            assert_eq!(bytes.as_slice(), body);
+        }
+        other => panic!("expected a UTF-8 error, got {:?}", other.map(|_| ())),
+    }
+}
+
+/// A summary keeps the fields that are commonly displayed, and survives a round
+/// trip through JSON.
+#[test]
+fn project_summary() {
+    let fixture = include_str!("fixtures/project.json");
+    let project: Project = serde_json::from_str(fixture).unwrap();
+    let summary = ProjectSummary::from(&project);
+
+    assert_eq!(summary.id, project.id);
+    assert_eq!(summary.main_file_id, 4593548);
+    assert_eq!(summary.primary_category_id, 421);
+    assert_eq!(summary.date_modified, project.date_modified);
+    assert_eq!(
+        summary.logo_url.as_deref(),
+        Some("https://media.forgecdn.net/avatars/29/69/635838945588716414.jpeg")
+    );
+    assert_eq!(summary, ProjectSummary::from(project));
+
+    let json = serde_json::to_string(&summary).unwrap();
+
+    assert!(json.contains("\"mainFileId\":4593548"));
+    assert_eq!(
+        serde_json::from_str::<ProjectSummary>(&json).unwrap(),
+        summary
+    );
+}
+
+/// The URLs of logos and screenshots may be null or empty for broken images.
+#[test]
+fn project_null_asset_urls() {
+    let fixture = include_str!("fixtures/project_null_asset_urls.json");
+    let project: Project = serde_json::from_str(fixture).unwrap();
+    let logo = project.logo.unwrap();
+
+    assert_eq!(logo.thumbnail_url, None);
+    assert!(logo.url.is_some());
+    assert_eq!(project.screenshots[0].thumbnail_url, None);
+    assert_eq!(project.screenshots[0].url, None);
+}
+
+/// Modpack manifests are read from downloaded files rather than the API, and
+/// may leave out optional fields.
+#[test]
+fn modpack_manifest() {
+    let fixture = include_str!("fixtures/modpack_manifest.json");
+    let manifest: ModpackManifest = serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(manifest.minecraft.version, "1.20.1");
+    assert!(manifest.minecraft.mod_loaders[0].primary);
+    assert_eq!(manifest.files.len(), 2);
+    assert!(!manifest.files[1].required);
+
+    let minimal = r#"{
+        "minecraft": { "version": "1.12.2" },
+        "manifestType": "minecraftModpack",
+        "manifestVersion": 1,
+        "name": "Minimal",
+        "files": [{ "projectID": 1, "fileID": 2 }]
+    }"#;
+    let manifest: ModpackManifest = serde_json::from_str(minimal).unwrap();
+
+    assert_eq!(manifest.author, None);
+    assert!(manifest.files[0].required);
+}
+
+/// Only projects in the class of modpacks are modpacks.
+#[test]
+fn project_is_modpack() {
+    let fixture = include_str!("fixtures/project.json");
+    let mut project: Project = serde_json::from_str(fixture).unwrap();
+
+    assert!(!project.is_modpack());
+
+    project.class_id = Some(4471);
+    assert!(project.is_modpack());
+}
+
+/// Some games are missing their icon or tile, which may be sent as empty
+/// strings, and the first asset that is present is displayed instead.
+#[test]
+fn game_missing_assets() {
+    let fixture = include_str!("fixtures/game_missing_assets.json");
+    let mut game: Game = serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(game.assets.icon_url, None);
+    assert_eq!(
+        game.display_icon(),
+        Some("https://media.forgecdn.net/game-covers/78022.png")
+    );
+
+    game.assets.tile_url = Some("tile.png".to_owned());
+    assert_eq!(game.display_icon(), Some("tile.png"));
+
+    game.assets.cover_url = None;
+    game.assets.tile_url = None;
+    assert_eq!(game.display_icon(), None);
+}
+
+/// A game is only available to every API key when it is both live and public.
+#[test]
+fn game_availability() {
+    let fixture = include_str!("fixtures/game_missing_assets.json");
+    let mut game: Game = serde_json::from_str(fixture).unwrap();
+
+    assert!(game.is_live() && game.api_public());
+
+    game.api_status = CoreApiStatus::Private;
+    assert!(!game.api_public());
+
+    game.status = CoreStatus::Approved;
+    assert!(!game.is_live());
+}
+
+/// The Minecraft endpoints have their own types, in which empty strings are
+/// treated as missing.
+#[test]
+fn minecraft_types() {
+    let fixture = include_str!("fixtures/minecraft_version.json");
+    let version: MinecraftGameVersion = serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(version.version_string, "1.20.1");
+    assert_eq!(version.game_version_status, GameVersionStatus::Approved);
+    assert!(version.jar_download_url.is_some());
+
+    let fixture = include_str!("fixtures/minecraft_modloader.json");
+    let loader: MinecraftModLoaderVersion = serde_json::from_str(fixture).unwrap();
+
+    assert_eq!(loader.loader_type, ModLoaderType::Forge);
+    assert_eq!(
+        loader.install_method,
+        ModLoaderInstallMethod::ForgeInstallerV2
+    );
+    assert_eq!(loader.libraries_install_location, None);
+    assert_eq!(loader.additional_files_json, None);
+    assert!(loader.recommended && !loader.latest);
+}
+
+/// Descriptions and changelogs are plain strings of HTML, and their newtypes
+/// can be used wherever a `String` was expected.
+#[test]
+fn html_newtypes() {
+    let uri = url::Url::parse("https://api.curseforge.com/v1/mods/1/description").unwrap();
+    let html = "<p>Just Enough Items</p>";
+
+    let response =
+        e::decode::<DataResponse<Description>>(uri, br#"{"data":"<p>Just Enough Items</p>"}"#)
+            .unwrap();
+    let description = response.data;
+
+    assert_eq!(description, html);
+    assert_eq!(description.to_string(), html);
+    assert_eq!(AsRef::<[u8]>::as_ref(&description), html.as_bytes());
+    assert!(description.starts_with("<p>"));
+
+    let string: String = description.into();
+    assert_eq!(string, html);
+
+    let changelog = Changelog::from(string);
+    assert_eq!(
+        serde_json::to_string(&changelog).unwrap(),
+        format!("{html:?}")
+    );
+    assert_eq!(changelog.into_string(), html);
+}
+
+/// Fingerprints that all matched are followed by `"unmatchedFingerprints":
+/// null`, rather than an empty list.
+#[test]
+fn fingerprint_matches() {
+    let fixture = include_str!("fixtures/fingerprint_matches.json");
+    let uri = url::Url::parse("https://api.curseforge.com/v1/fingerprints/432").unwrap();
+    let result = e::decode::<DataResponse<FingerprintsMatchesResult>>(uri, fixture.as_bytes())
+        .unwrap()
+        .data;
+
+    let matched = &result.exact_matches[0];
+
+    assert_eq!(matched.id, matched.file.project_id);
+    assert_eq!(result.exact_fingerprints, [matched.file.file_fingerprint]);
+    assert_eq!(result.installed_fingerprints[1], i64::from(u32::MAX));
+    assert!(result.unmatched_fingerprints.is_empty());
+}
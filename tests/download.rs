@@ -0,0 +1,156 @@
+//! These tests cover downloads from the CDN, against a local server that
+//! answers each connection with the next of a list of responses.
+
+use curseforge::official::prelude::*;
+use curseforge::Error;
+
+mod common;
+
+fn asset(url: String) -> ProjectAsset {
+    serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "modId": 238222,
+        "title": "Logo",
+        "description": null,
+        "thumbnailUrl": null,
+        "url": url,
+    }))
+    .unwrap()
+}
+
+/// A download that redirects to a mirror is followed, unless redirects have
+/// been turned off.
+#[test]
+fn download_redirect() {
+    const REDIRECT: &str = "HTTP/1.1 302 Found\r\nlocation: /mirror/logo.png\r\ncontent-length: \
+                            0\r\nconnection: close\r\n\r\n";
+
+    let (url, _) = common::serve(&[
+        REDIRECT,
+        "HTTP/1.1 200 OK\r\ncontent-length: 4\r\nconnection: close\r\n\r\nlogo",
+        REDIRECT,
+    ]);
+    let asset = asset(format!("{}logo.png", url));
+
+    smol::block_on(async {
+        let client = Client::builder().build().unwrap();
+        let mut bytes = Vec::new();
+
+        assert_eq!(
+            client.download_asset(&asset, &mut bytes).await.unwrap(),
+            Some(4)
+        );
+        assert_eq!(bytes, b"logo");
+
+        let client = Client::builder().max_redirects(0).build().unwrap();
+        let error = client.download_asset(&asset, Vec::new()).await.unwrap_err();
+
+        assert!(matches!(error, Error::StatusNotOk { status, .. } if status == 302));
+    });
+}
+
+/// Every file of a manifest is checked where it was downloaded to, and a file
+/// that the API did not return is reported rather than left out.
+#[test]
+fn verify_manifest_files() {
+    let mut file: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/file_relative_download_url.json")).unwrap();
+    file["id"] = 1.into();
+    file["fileName"] = "verified.jar".into();
+    file["hashes"] = serde_json::json!([
+        { "value": "2aae6c35c94fcfb415dbe95f408b9ce91ee846ed", "algo": 1 },
+    ]);
+
+    let body = serde_json::json!({ "data": [file] }).to_string();
+    let (url, requests) = common::serve([common::json("200 OK", &body)]);
+    let client = Client::new(format!("{}v1/", url), None, None).unwrap();
+    let manifest = manifest(&[(10, 1), (20, 2)]);
+    let dir = std::env::temp_dir().join("curseforge-verify-manifest");
+
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("verified.jar"), b"hello world").unwrap();
+
+    let results = smol::block_on(client.verify_manifest_files(&manifest, &dir)).unwrap();
+
+    assert_eq!(requests.recv().unwrap(), "POST /v1/mods/files HTTP/1.1");
+    assert_eq!(results.len(), 2);
+    assert!(matches!(results[0], (10, 1, Ok(()))));
+    assert!(matches!(results[1], (20, 2, Err(HashMismatch::Unresolved))));
+}
+
+/// Formats a response to a request for several files, each a copy of the
+/// fixture with one of `files`, given as an ID and a name.
+fn files_response(files: &[(i32, &str)]) -> String {
+    let fixture: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/file_relative_download_url.json")).unwrap();
+    let data = files
+        .iter()
+        .map(|(id, name)| {
+            let mut file = fixture.clone();
+            file["id"] = (*id).into();
+            file["fileName"] = (*name).into();
+            file
+        })
+        .collect::<Vec<_>>();
+
+    common::json("200 OK", &serde_json::json!({ "data": data }).to_string())
+}
+
+fn manifest(files: &[(i32, i32)]) -> ModpackManifest {
+    let files = files
+        .iter()
+        .map(|(project_id, file_id)| serde_json::json!({ "projectID": project_id, "fileID": file_id }))
+        .collect::<Vec<_>>();
+
+    serde_json::from_value(serde_json::json!({
+        "minecraft": { "version": "1.20.1", "modLoaders": [] },
+        "manifestType": "minecraftModpack",
+        "manifestVersion": 1,
+        "name": "Sample",
+        "files": files,
+    }))
+    .unwrap()
+}
+
+/// The files of a manifest that the API did not return are reported, rather
+/// than left out of the targets.
+#[test]
+fn manifest_download_targets_unresolved() {
+    let (url, _) = common::serve([files_response(&[(1, "a.jar"), (3, "c.jar")])]);
+    let client = Client::new(format!("{}v1/", url), None, None).unwrap();
+    let dir = std::path::Path::new("mods");
+
+    let targets = smol::block_on(
+        client.manifest_download_targets(&manifest(&[(10, 1), (20, 2), (30, 3)]), dir),
+    )
+    .unwrap();
+
+    assert_eq!(
+        targets,
+        DownloadTargets {
+            targets: vec![(10, 1, dir.join("a.jar")), (30, 3, dir.join("c.jar"))],
+            unresolved: vec![(20, 2)],
+        }
+    );
+}
+
+/// Two files whose names differ only in case would overwrite each other on
+/// some filesystems, so they are rejected.
+#[test]
+fn manifest_download_targets_collision() {
+    let (url, _) = common::serve([files_response(&[(1, "Mod.jar"), (2, "mod.jar")])]);
+    let client = Client::new(format!("{}v1/", url), None, None).unwrap();
+    let dir = std::path::Path::new("mods");
+
+    let result =
+        smol::block_on(client.manifest_download_targets(&manifest(&[(10, 1), (20, 2)]), dir));
+
+    assert!(matches!(
+        result,
+        Err(Error::TargetCollision {
+            first: 1,
+            second: 2,
+            ..
+        })
+    ));
+}
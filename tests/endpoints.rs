@@ -9,11 +9,13 @@ static PROXY_API_BASE: &str = "https://api.curse.tools/v1/cf/";
 static TOKEN_VARIABLE: &str = "CURSEFORGE_API_TOKEN";
 
 /// Settings are lowered to reduce API spam.
-static CLIENT_OPTIONS: ClientOptions = ClientOptions {
+static CLIENT_OPTIONS: Lazy<ClientOptions> = Lazy::new(|| {
+    let mut options = ClientOptions::default();
     // This is the maximum number of client connections allowed for the host.
     // Increasing this number may result in denial errors.
-    max_connections: 1,
-};
+    options.max_connections = 1;
+    options
+});
 
 const GAME_TERRARIA: i32 = 431;
 const GAME_MINECRAFT: i32 = 432;
@@ -21,11 +23,11 @@ const GAME_MINECRAFT: i32 = 432;
 static CLIENT: Lazy<Client> = Lazy::new(|| match std::env::var(TOKEN_VARIABLE) {
     Ok(token) => {
         eprintln!("Using official CurseForge API with token.");
-        Client::new(e::DEFAULT_API_BASE, Some(token), Some(&CLIENT_OPTIONS)).unwrap()
+        Client::new(e::DEFAULT_API_BASE, Some(token), Some(&*CLIENT_OPTIONS)).unwrap()
     }
     Err(_) => {
         eprintln!("Using proxy for CurseForge API without token.");
-        Client::new(PROXY_API_BASE, None, Some(&CLIENT_OPTIONS)).unwrap()
+        Client::new(PROXY_API_BASE, None, Some(&*CLIENT_OPTIONS)).unwrap()
     }
 });
 
@@ -64,6 +66,32 @@ fn game() {
     });
 }
 
+/// Example requests the versions of Minecraft and the recommended mod
+/// loaders, and then the details of the first recommended loader.
+#[test]
+fn minecraft_versions_and_modloaders() {
+    smol::block_on(async {
+        match CLIENT.minecraft_versions().await {
+            Ok(versions) => assert!(!versions.is_empty()),
+            Err(error) => panic!("{}", error),
+        }
+
+        let loaders = match CLIENT.minecraft_modloaders().await {
+            Ok(loaders) => loaders,
+            Err(error) => panic!("{}", error),
+        };
+        let recommended = loaders
+            .iter()
+            .find(|loader| loader.recommended)
+            .expect("no recommended mod loader");
+
+        match CLIENT.minecraft_modloader(&recommended.name).await {
+            Ok(loader) => assert_eq!(loader.name, recommended.name),
+            Err(error) => panic!("{}", error),
+        }
+    });
+}
+
 /// Example performs a request for all games supported by the API.
 #[test]
 fn games() {
@@ -78,6 +106,21 @@ fn games() {
     });
 }
 
+/// Example requests the games that can be queried with any API key, which
+/// include Minecraft.
+#[test]
+fn available_games() {
+    smol::block_on(async {
+        let games = match CLIENT.available_games().await {
+            Ok(games) => games,
+            Err(error) => panic!("{}", error),
+        };
+
+        assert!(games.iter().all(|game| game.api_public() && game.is_live()));
+        assert!(games.iter().any(|game| game.id == GAME_MINECRAFT));
+    });
+}
+
 /// Example performs a request for the versions of a game by its ID. The `type`
 /// field corresponds to a "version type", for example, the version of a
 /// modloader, or a major release for a game.
@@ -139,6 +182,49 @@ fn search_projects() {
     });
 }
 
+/// Example replays the query string of a search, as it would be copied from a
+/// log, and checks that it returns the same page as the parameters did.
+#[test]
+fn search_projects_raw_query() {
+    smol::block_on(async {
+        let params = ProjectSearchParams::game(GAME_MINECRAFT);
+        let query = format!("?{}", params.query_string());
+
+        let expected = CLIENT.search_projects(&params).await.unwrap();
+        let result = CLIENT.search_projects_raw_query(&query).await.unwrap();
+
+        assert_eq!(result.pagination.index, expected.pagination.index);
+        assert_eq!(result.data.len(), expected.data.len());
+    });
+}
+
+/// Example searches through a client with a default page size, and checks that
+/// the page size of a single call takes precedence.
+#[test]
+fn with_default_params() {
+    smol::block_on(async {
+        let defaults = DefaultParams {
+            search: Some(ProjectSearchDefaults {
+                page_size: Some(5),
+                ..Default::default()
+            }),
+            files: None,
+        };
+        let client = CLIENT.with_default_params(defaults);
+
+        let params = ProjectSearchParams::game(GAME_MINECRAFT);
+        let result = client.search_projects(&params).await.unwrap();
+        assert_eq!(result.data.len(), 5);
+
+        let params = ProjectSearchParams {
+            page_size: Some(10),
+            ..params
+        };
+        let result = client.search_projects(&params).await.unwrap();
+        assert_eq!(result.data.len(), 10);
+    });
+}
+
 /// Example asynchronously paginates over the maximum allowed search results
 /// (10,000) for the game Minecraft. This demonstrates proper deserialization
 /// into the wrapper's types as well as the proper usage of `PaginatedStream`.
@@ -147,6 +233,56 @@ fn search_projects_iter() {
     assert!(!SAMPLE_PROJECTS.is_empty())
 }
 
+/// Example polls a sample project for changes since it was last modified, which
+/// finds none, and since a moment before, which returns the project.
+#[test]
+fn project_if_modified_since() {
+    smol::block_on(async {
+        let project = &SAMPLE_PROJECTS[0];
+        let since = project.date_modified;
+
+        assert!(CLIENT
+            .project_if_modified_since(project.id, since)
+            .await
+            .unwrap()
+            .is_none());
+
+        let since = since - chrono::Duration::seconds(1);
+        let result = CLIENT.project_if_modified_since(project.id, since).await;
+
+        assert_eq!(result.unwrap().unwrap().id, project.id);
+    });
+}
+
+/// Example checks that a sample project and its main file exist, and that a
+/// project with an ID that is never assigned does not.
+#[test]
+fn project_exists() {
+    smol::block_on(async {
+        let project = &SAMPLE_PROJECTS[0];
+
+        assert!(CLIENT.project_exists(project.id).await.unwrap());
+        assert!(CLIENT
+            .file_exists(project.id, project.main_file_id)
+            .await
+            .unwrap());
+        assert!(!CLIENT.project_exists(i32::MAX).await.unwrap());
+    });
+}
+
+/// Example requests the newest file of the first project from a sample search.
+#[test]
+fn project_newest_file() {
+    smol::block_on(async {
+        let project = &SAMPLE_PROJECTS[0];
+
+        match CLIENT.project_newest_file(project.id).await {
+            Ok(file) => assert_eq!(file.unwrap().project_id, project.id),
+            Err(error) => panic!("{}", error),
+        }
+    });
+}
+
 /// Example performs a request for the data from the first 500 projects
 /// returned from a sample search, by their ID.
 #[test]
@@ -183,6 +319,29 @@ fn projects() {
     });
 }
 
+/// Example requests the first 3000 projects from a sample search in chunks of
+/// 500, and then requests the main file of each in the same way.
+#[test]
+fn projects_chunked() {
+    smol::block_on(async {
+        let projects = &SAMPLE_PROJECTS[..3000];
+        let project_ids = projects.iter().map(|project| project.id);
+        let result = CLIENT
+            .projects_chunked(project_ids, 500, Concurrency::Client)
+            .await;
+
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(result.items.len(), projects.len());
+
+        let file_ids = projects.iter().map(|project| project.main_file_id);
+        let result = CLIENT
+            .project_files_by_ids_chunked(file_ids, 500, Concurrency::Client)
+            .await;
+
+        assert!(result.is_complete(), "{:?}", result.errors);
+    });
+}
+
 /// Example makes a request with default parameters for getting featured
 /// projects for te game Minecraft.
 #[test]
@@ -310,6 +469,544 @@ fn project_files_iter() {
     });
 }
 
+/// Example lists the first files of a sample project, and then resumes the
+/// listing from a checkpoint, which should continue where the first left off.
+#[test]
+fn project_files_iter_from() {
+    use smol::stream::StreamExt;
+
+    smol::block_on(async {
+        let project = &SAMPLE_PROJECTS[0];
+        let params = ProjectFilesParams::default();
+
+        let files = CLIENT
+            .project_files_iter(project.id, params.clone())
+            .take(10)
+            .try_collect::<_, _, Vec<_>>()
+            .await
+            .unwrap();
+        let resumed = CLIENT
+            .project_files_iter_from(project.id, params, 5)
+            .take(5)
+            .try_collect::<_, _, Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            files[5..].iter().map(|file| file.id).collect::<Vec<_>>(),
+            resumed.iter().map(|file| file.id).collect::<Vec<_>>()
+        );
+    });
+}
+
+/// Example retrieves only the release files of some sample projects, which is
+/// filtered by the client rather than the API.
+#[test]
+fn project_files_iter_releases() {
+    use smol::pin;
+    use smol::stream::StreamExt;
+
+    smol::block_on(async {
+        let params = ProjectFilesParams::default().release_type(FileReleaseType::Release);
+
+        let projects = &SAMPLE_PROJECTS[..20];
+        let project_ids = projects.iter().map(|project| project.id);
+
+        for project in project_ids {
+            let files = CLIENT.project_files_iter(project, params.clone());
+            pin!(files);
+
+            while let Some(result) = files.next().await {
+                match result {
+                    Ok(file) => assert_eq!(file.release_type, FileReleaseType::Release),
+                    Err(error) => panic!("{}", error),
+                }
+            }
+        }
+    });
+}
+
+/// Example lists every file of some sample projects, which are yielded newest
+/// first.
+#[test]
+fn project_files_newest_first() {
+    use smol::stream::StreamExt;
+
+    smol::block_on(async {
+        for project in &SAMPLE_PROJECTS[..20] {
+            let files = CLIENT
+                .project_files_newest_first(project.id)
+                .try_collect::<_, _, Vec<_>>()
+                .await
+                .unwrap();
+
+            assert!(files
+                .windows(2)
+                .all(|pair| pair[0].file_date >= pair[1].file_date));
+        }
+    });
+}
+
+/// Example requests the complete file for every index of some sample projects.
+#[test]
+fn files_from_indexes() {
+    smol::block_on(async {
+        for project in &SAMPLE_PROJECTS[..20] {
+            let files = CLIENT
+                .files_from_indexes(&project.latest_files_indexes)
+                .await;
+
+            match files {
+                Ok(files) => assert!(files.iter().all(|file| project
+                    .latest_files_indexes
+                    .iter()
+                    .any(|index| index.file_id == file.id))),
+                Err(error) => panic!("{}", error),
+            }
+        }
+    });
+}
+
+/// Example downloads the smallest of the latest files of some sample projects,
+/// and checks that the size on disk is the size reported by the API.
+#[test]
+fn download_file_to() {
+    smol::block_on(async {
+        let file = SAMPLE_PROJECTS[..20]
+            .iter()
+            .flat_map(|project| &project.latest_files)
+            .filter(|file| file.file_length > 0)
+            .min_by_key(|file| file.file_length)
+            .unwrap();
+
+        let dir = std::env::temp_dir().join("curseforge-download-file-to");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = match CLIENT.download_file_to(file, &dir).await {
+            Ok(path) => path,
+            Err(error) => panic!("{}", error),
+        };
+        let size = std::fs::metadata(&path).unwrap().len();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(size as i64, file.file_length);
+    });
+}
+
+/// Example downloads the smallest of the latest files of some sample projects
+/// into memory, starting from the file rather than from the client.
+#[test]
+fn project_file_download() {
+    smol::block_on(async {
+        let file = SAMPLE_PROJECTS[..20]
+            .iter()
+            .flat_map(|project| &project.latest_files)
+            .filter(|file| file.file_length > 0)
+            .min_by_key(|file| file.file_length)
+            .unwrap();
+
+        let mut bytes = Vec::new();
+        let written = match file.download(&CLIENT, &mut bytes).await {
+            Ok(written) => written,
+            Err(error) => panic!("{}", error),
+        };
+
+        assert_eq!(written as usize, bytes.len());
+        assert_eq!(written as i64, file.file_length);
+    });
+}
+
+/// Example downloads the logo of a sample project that has one, which is a
+/// PNG or JPEG image.
+#[test]
+fn project_logo() {
+    smol::block_on(async {
+        let project = SAMPLE_PROJECTS
+            .iter()
+            .find(|project| matches!(&project.logo, Some(logo) if logo.url.is_some()))
+            .unwrap();
+
+        let bytes = match CLIENT.project_logo(project).await {
+            Ok(bytes) => bytes.unwrap(),
+            Err(error) => panic!("{}", error),
+        };
+
+        assert!(bytes.starts_with(b"\x89PNG") || bytes.starts_with(b"\xff\xd8"));
+    });
+}
+
+/// Example matches the fingerprints of the latest files of some sample projects
+/// against the files of every game, along with one that matches nothing.
+#[test]
+fn fingerprint_matches() {
+    smol::block_on(async {
+        let files = SAMPLE_PROJECTS[..20]
+            .iter()
+            .flat_map(|project| project.latest_files.first())
+            .collect::<Vec<_>>();
+        let fingerprints = files
+            .iter()
+            .map(|file| file.file_fingerprint)
+            .chain(std::iter::once(1));
+
+        let result = match CLIENT.fingerprint_matches(fingerprints).await {
+            Ok(result) => result,
+            Err(error) => panic!("{}", error),
+        };
+
+        for file in files {
+            assert!(result
+                .exact_matches
+                .iter()
+                .any(|found| found.file.id == file.id));
+        }
+
+        assert!(result.unmatched_fingerprints.contains(&1));
+    });
+}
+
+/// Example matches the fingerprints of the latest files of some sample projects
+/// against the files of Minecraft, and checks that each file is found.
+#[test]
+fn fingerprint_matches_for_game() {
+    smol::block_on(async {
+        let files = SAMPLE_PROJECTS[..20]
+            .iter()
+            .flat_map(|project| project.latest_files.first())
+            .collect::<Vec<_>>();
+        let fingerprints = files.iter().map(|file| file.file_fingerprint);
+
+        let result = match CLIENT
+            .fingerprint_matches_for_game(GAME_MINECRAFT, fingerprints)
+            .await
+        {
+            Ok(result) => result,
+            Err(error) => panic!("{}", error),
+        };
+
+        for file in files {
+            assert!(result.exact_fingerprints.contains(&file.file_fingerprint));
+        }
+    });
+}
+
+/// Example builds the file relationship graph of the first project from a
+/// sample search, and checks that every server pack found belongs to a file.
+#[test]
+fn file_relationship_graph() {
+    smol::block_on(async {
+        let project = &SAMPLE_PROJECTS[0];
+        let graph = match CLIENT.file_relationship_graph(project.id).await {
+            Ok(graph) => graph,
+            Err(error) => panic!("{}", error),
+        };
+
+        assert!(graph.file(project.main_file_id).is_some());
+
+        for file in graph.files() {
+            if let Some(server_pack) = graph.server_pack_of(file.id) {
+                assert!(server_pack.is_server_pack);
+            }
+        }
+    });
+}
+
+/// Example lists the files of a sample project for the version type of one of
+/// its latest files, by the name of that type, and checks that a name that no
+/// type has is an error.
+#[test]
+fn files_by_version_type() {
+    smol::block_on(async {
+        let project = &SAMPLE_PROJECTS[0];
+        let index = project
+            .latest_files_indexes
+            .iter()
+            .find(|index| index.game_version_type_id.is_some())
+            .unwrap();
+        let version_types = match CLIENT.game_version_types(project.game_id).await {
+            Ok(version_types) => version_types,
+            Err(error) => panic!("{}", error),
+        };
+        let version_type = version_types
+            .iter()
+            .find(|version_type| Some(version_type.id) == index.game_version_type_id)
+            .unwrap();
+
+        let result = CLIENT
+            .files_by_version_type(
+                project.id,
+                &version_type.name.to_uppercase(),
+                ProjectFilesParams::default(),
+            )
+            .await;
+
+        match result {
+            Ok(files) => assert!(files.iter().any(|file| file.id == index.file_id)),
+            Err(error) => panic!("{}", error),
+        }
+
+        let result = CLIENT
+            .files_by_version_type(project.id, "Not A Version", ProjectFilesParams::default())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(curseforge::Error::UnknownVersionType { .. })
+        ));
+    });
+}
+
+/// Example sums the size of a manifest made from the main files of some sample
+/// projects, and checks it against the sizes reported by the search.
+#[test]
+fn manifest_download_size() {
+    smol::block_on(async {
+        let projects = &SAMPLE_PROJECTS[..20];
+        let files = projects
+            .iter()
+            .flat_map(|project| {
+                project
+                    .latest_files
+                    .iter()
+                    .find(|file| file.id == project.main_file_id)
+            })
+            .collect::<Vec<_>>();
+        let manifest = ModpackManifest {
+            minecraft: ManifestMinecraft {
+                version: "1.20.1".to_owned(),
+                mod_loaders: Vec::new(),
+            },
+            manifest_type: "minecraftModpack".to_owned(),
+            manifest_version: 1,
+            name: "Sample".to_owned(),
+            version: None,
+            author: None,
+            files: files
+                .iter()
+                .map(|file| ManifestFile {
+                    project_id: file.project_id,
+                    file_id: file.id,
+                    required: true,
+                })
+                .collect(),
+            overrides: None,
+        };
+
+        let size = match CLIENT.manifest_download_size(&manifest).await {
+            Ok(size) => size,
+            Err(error) => panic!("{}", error),
+        };
+        let expected = files
+            .iter()
+            .map(|file| file.file_length.max(0))
+            .sum::<i64>();
+
+        assert_eq!(size, expected);
+    });
+}
+
+/// Example plans the downloads of a manifest made from the main files of some
+/// sample projects, which keep the order of the manifest.
+#[test]
+fn manifest_download_targets() {
+    smol::block_on(async {
+        let files = SAMPLE_PROJECTS[..20]
+            .iter()
+            .map(|project| {
+                serde_json::json!({
+                    "projectID": project.id,
+                    "fileID": project.main_file_id,
+                })
+            })
+            .collect::<Vec<_>>();
+        let manifest: ModpackManifest = serde_json::from_value(serde_json::json!({
+            "minecraft": { "version": "1.20.1", "modLoaders": [] },
+            "manifestType": "minecraftModpack",
+            "manifestVersion": 1,
+            "name": "Sample",
+            "files": files,
+        }))
+        .unwrap();
+        let dir = std::env::temp_dir().join("curseforge-manifest-targets");
+
+        let targets = match CLIENT.manifest_download_targets(&manifest, &dir).await {
+            Ok(targets) => targets,
+            Err(error) => panic!("{}", error),
+        };
+        let expected = manifest
+            .files
+            .iter()
+            .map(|file| (file.project_id, file.file_id))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            targets
+                .targets
+                .iter()
+                .map(|(project_id, file_id, _)| (*project_id, *file_id))
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert!(targets
+            .targets
+            .iter()
+            .all(|(_, _, path)| path.parent() == Some(dir.as_path())));
+        assert!(targets.unresolved.is_empty());
+    });
+}
+
+/// Example checks the files of a manifest in a directory that they were never
+/// downloaded to, so that every file is reported as missing, in order.
+#[test]
+fn verify_manifest_files() {
+    smol::block_on(async {
+        let files = SAMPLE_PROJECTS[..10]
+            .iter()
+            .map(|project| {
+                serde_json::json!({
+                    "projectID": project.id,
+                    "fileID": project.main_file_id,
+                })
+            })
+            .collect::<Vec<_>>();
+        let manifest: ModpackManifest = serde_json::from_value(serde_json::json!({
+            "minecraft": { "version": "1.20.1", "modLoaders": [] },
+            "manifestType": "minecraftModpack",
+            "manifestVersion": 1,
+            "name": "Sample",
+            "files": files,
+        }))
+        .unwrap();
+        let dir = std::env::temp_dir().join("curseforge-verify-missing");
+
+        let results = match CLIENT.verify_manifest_files(&manifest, &dir).await {
+            Ok(results) => results,
+            Err(error) => panic!("{}", error),
+        };
+
+        assert_eq!(results.len(), manifest.files.len());
+        assert!(results
+            .iter()
+            .all(|(_, _, result)| matches!(result, Err(HashMismatch::Io(_)))));
+    });
+}
+
+/// Example finds the author of a sample project by name, and then lists every
+/// one of their projects.
+#[test]
+fn author_projects() {
+    use smol::pin;
+    use smol::stream::StreamExt;
+
+    smol::block_on(async {
+        let expected = &SAMPLE_PROJECTS[0].authors[0];
+
+        let author = match CLIENT.author_by_name(GAME_MINECRAFT, &expected.name).await {
+            Ok(author) => author.unwrap(),
+            Err(error) => panic!("{}", error),
+        };
+        assert_eq!(author.id, expected.id);
+
+        let projects = CLIENT.author_projects(GAME_MINECRAFT, author.id);
+        pin!(projects);
+
+        while let Some(result) = projects.next().await {
+            match result {
+                Ok(project) => assert!(project.authors.iter().any(|a| a.id == author.id)),
+                Err(error) => panic!("{}", error),
+            }
+        }
+    });
+}
+
+/// Example paginates over the first 200 search results for Minecraft,
+/// retrying any page that fails with a transient error.
+#[test]
+fn search_projects_iter_retrying() {
+    use smol::pin;
+    use smol::stream::StreamExt;
+
+    smol::block_on(async {
+        let params = ProjectSearchParams::game(GAME_MINECRAFT);
+        let projects = CLIENT
+            .search_projects_iter_retrying(params, 3, std::time::Duration::from_secs(1))
+            .take(200);
+        pin!(projects);
+
+        while let Some(result) = projects.next().await {
+            if let Err(error) = result {
+                panic!("{}", error);
+            }
+        }
+    });
+}
+
+/// Example requests the newest projects of Minecraft, which are sorted by the
+/// date that they were created.
+#[test]
+fn newest_projects() {
+    smol::block_on(async {
+        let projects = match CLIENT.newest_projects(GAME_MINECRAFT, 20).await {
+            Ok(projects) => projects,
+            Err(error) => panic!("{}", error),
+        };
+
+        assert_eq!(projects.len(), 20);
+        assert!(projects
+            .windows(2)
+            .all(|pair| pair[0].date_created >= pair[1].date_created));
+    });
+}
+
+/// Example takes the first 500 projects of the catalog of Terraria, which are
+/// never repeated.
+#[test]
+fn all_projects_for_game() {
+    use smol::stream::StreamExt;
+
+    smol::block_on(async {
+        let projects = CLIENT
+            .all_projects_for_game(GAME_TERRARIA)
+            .take(500)
+            .try_collect::<_, _, Vec<_>>()
+            .await
+            .unwrap();
+        let ids = projects
+            .iter()
+            .map(|project| project.id)
+            .collect::<std::collections::HashSet<_>>();
+
+        assert_eq!(ids.len(), projects.len());
+    });
+}
+
+/// Example collects every result of a narrow search, recording the progress
+/// reported after each page.
+#[test]
+fn search_projects_collect_with_progress() {
+    smol::block_on(async {
+        let mut params = ProjectSearchParams::game(GAME_MINECRAFT);
+        params.search_filter = Some("jei".to_owned());
+
+        let mut reports = Vec::new();
+        let result = CLIENT
+            .search_projects_collect_with_progress(params, |fetched, total| {
+                reports.push((fetched, total))
+            })
+            .await;
+
+        match result {
+            Ok(projects) => {
+                let (fetched, total) = *reports.last().unwrap();
+
+                assert_eq!(fetched, projects.len());
+                assert_eq!(total, Some(projects.len()));
+            }
+            Err(error) => panic!("{}", error),
+        }
+    });
+}
+
 /// Example makes requests for every project's main file for the first 3000
 /// projects returned form a sample search.
 #[test]
@@ -379,3 +1076,271 @@ fn project_file_download_url() {
         }
     });
 }
+
+/// Example wraps the client in a `GameMetadataCache` and requests the
+/// categories for the game Minecraft twice, the second being served from
+/// memory.
+#[test]
+fn game_metadata_cache() {
+    use std::time::Duration;
+
+    smol::block_on(async {
+        let cache = GameMetadataCache::new(CLIENT.clone(), Duration::from_secs(60));
+        let first = cache.cached_categories(GAME_MINECRAFT).await;
+        let second = cache.cached_categories(GAME_MINECRAFT).await;
+
+        match (first, second) {
+            (Ok(first), Ok(second)) => assert_eq!(first, second),
+            (Err(error), _) | (_, Err(error)) => panic!("{}", error),
+        }
+    });
+}
+
+/// Example resolves a link to a project page on the CurseForge website to the
+/// project that it refers to.
+#[test]
+fn project_from_url() {
+    smol::block_on(async {
+        let result = CLIENT
+            .project_from_url("https://www.curseforge.com/minecraft/mc-mods/jei")
+            .await;
+
+        match result {
+            Ok(Some(project)) => assert_eq!(project.slug, "jei"),
+            Ok(None) => panic!("project was not found"),
+            Err(error) => panic!("{}", error),
+        }
+    });
+}
+
+/// Example treats the main file of the first 20 projects from a sample search
+/// as installed, and checks each of them for a newer file and its changelog.
+#[test]
+fn update_candidates() {
+    smol::block_on(async {
+        let projects = &SAMPLE_PROJECTS[..20];
+        let installed = projects
+            .iter()
+            .map(|project| (project.id, project.main_file_id))
+            .collect::<Vec<_>>();
+
+        let result = CLIENT
+            .update_candidates(&installed, None, None, CLIENT_OPTIONS.max_connections)
+            .await;
+
+        match result {
+            Ok(candidates) => assert_eq!(candidates.len(), installed.len()),
+            Err(error) => panic!("{}", error),
+        }
+    });
+}
+
+/// Example checks whether the main files of some sample projects are the
+/// newest available, reporting a status for each.
+#[test]
+fn check_updates() {
+    smol::block_on(async {
+        let projects = &SAMPLE_PROJECTS[..20];
+        let installed = projects
+            .iter()
+            .map(|project| (project.id, project.main_file_id))
+            .collect::<Vec<_>>();
+
+        let statuses = CLIENT
+            .check_updates(&installed, None, None, CLIENT_OPTIONS.max_connections)
+            .await;
+
+        assert_eq!(statuses.len(), installed.len());
+
+        for status in statuses {
+            if let ModUpdateStatus::Error(error) = status {
+                panic!("{}", error);
+            }
+        }
+    });
+}
+
+/// Example constructs a client that logs the fields of every response that
+/// are not known, which does not change the values that are returned.
+#[test]
+fn log_unknown_fields() {
+    smol::block_on(async {
+        let mut options = CLIENT_OPTIONS.clone();
+        options.log_unknown_fields = true;
+
+        let client = match std::env::var(TOKEN_VARIABLE) {
+            Ok(token) => Client::new(e::DEFAULT_API_BASE, Some(token), Some(&options)),
+            Err(_) => Client::new(PROXY_API_BASE, None, Some(&options)),
+        }
+        .unwrap();
+
+        let game = match client.game(GAME_TERRARIA).await {
+            Ok(game) => game,
+            Err(error) => panic!("{}", error),
+        };
+
+        assert_eq!(game, CLIENT.game(GAME_TERRARIA).await.unwrap());
+    });
+}
+
+/// Example constructs a client that writes every response body to a temporary
+/// directory, and checks that a request for a game produced a file.
+#[test]
+fn dump_responses() {
+    smol::block_on(async {
+        let dir = std::env::temp_dir().join("curseforge-dump-responses");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut options = CLIENT_OPTIONS.clone();
+        options.dump_responses = Some(dir.clone());
+
+        let client = match std::env::var(TOKEN_VARIABLE) {
+            Ok(token) => Client::new(e::DEFAULT_API_BASE, Some(token), Some(&options)),
+            Err(_) => Client::new(PROXY_API_BASE, None, Some(&options)),
+        }
+        .unwrap();
+
+        match client.game(GAME_TERRARIA).await {
+            Ok(_game) => (),
+            Err(error) => panic!("{}", error),
+        }
+
+        let dumped = std::fs::read_dir(&dir).unwrap().count();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(dumped, 1);
+    });
+}
+
+/// Example performs a request for a game by ID through the timed view of the
+/// client, which also reports how long the request took.
+#[test]
+fn timed_game() {
+    smol::block_on(async {
+        let game = CLIENT.timed().game(GAME_TERRARIA).await;
+
+        match &game {
+            Ok(_game) => (), /* println!("{:?}", game.elapsed) */
+            Err(error) => panic!("{}", error),
+        }
+    });
+}
+
+/// Example reads the manifest of the main file of a popular modpack, and
+/// resolves every file that it lists.
+#[cfg(feature = "modpack")]
+#[test]
+fn modpack_contents() {
+    smol::block_on(async {
+        let mut params = ProjectSearchParams::game(GAME_MINECRAFT);
+        params.class_id = Some(4471);
+        params.page_size = Some(1);
+
+        let modpack = match CLIENT.search_projects(&params).await {
+            Ok(response) => response.data.into_iter().next().unwrap(),
+            Err(error) => panic!("{}", error),
+        };
+        assert!(modpack.is_modpack());
+
+        let manifest = match CLIENT
+            .modpack_manifest(modpack.id, modpack.main_file_id)
+            .await
+        {
+            Ok(manifest) => manifest,
+            Err(error) => panic!("{}", error),
+        };
+
+        match CLIENT
+            .modpack_contents(modpack.id, modpack.main_file_id)
+            .await
+        {
+            Ok(contents) => assert!(contents.len() <= manifest.files.len()),
+            Err(error) => panic!("{}", error),
+        }
+    });
+}
+
+/// Example performs concurrent requests for both Terraria and Minecraft by
+/// their IDs.
+#[test]
+fn games_by_ids() {
+    smol::block_on(async {
+        let games = CLIENT
+            .games_by_ids(
+                [GAME_TERRARIA, GAME_MINECRAFT],
+                CLIENT_OPTIONS.max_connections,
+            )
+            .await;
+
+        for (game_id, result) in games {
+            match result {
+                Ok(game) => assert_eq!(game.id, game_id),
+                Err(error) => panic!("{}", error),
+            }
+        }
+    });
+}
+
+/// Example performs concurrent requests for the categories of both Terraria
+/// and Minecraft.
+#[test]
+fn categories_for_games() {
+    smol::block_on(async {
+        let categories = CLIENT
+            .categories_for_games(
+                [GAME_TERRARIA, GAME_MINECRAFT],
+                CLIENT_OPTIONS.max_connections,
+            )
+            .await;
+
+        for (game_id, result) in categories {
+            match result {
+                Ok(categories) => assert!(categories
+                    .iter()
+                    .all(|category| category.game_id == game_id)),
+                Err(error) => panic!("{}", error),
+            }
+        }
+    });
+}
+
+/// Example streams the changelogs of the five newest files of the first
+/// project from a sample search.
+#[test]
+fn project_changelog_stream() {
+    use smol::pin;
+    use smol::stream::StreamExt;
+
+    smol::block_on(async {
+        let project = &SAMPLE_PROJECTS[0];
+        let changelogs = CLIENT
+            .project_changelog_stream(project.id, CLIENT_OPTIONS.max_connections)
+            .take(5);
+        pin!(changelogs);
+
+        while let Some(result) = changelogs.next().await {
+            match result {
+                Ok((_file, _changelog)) => (), /* println!("{}", changelog) */
+                Err(error) => panic!("{}", error),
+            }
+        }
+    });
+}
+
+/// Example searches for projects for the game Minecraft, and counts the
+/// projects on the first page by category and loader.
+#[test]
+fn search_with_facets() {
+    smol::block_on(async {
+        let params = ProjectSearchParams::game(GAME_MINECRAFT);
+        let result = CLIENT.search_with_facets(&params).await;
+
+        match result {
+            Ok(results) => {
+                let counted = results.category_counts.values().sum::<usize>();
+                assert_eq!(counted, results.projects.len());
+            }
+            Err(error) => panic!("{}", error),
+        }
+    });
+}
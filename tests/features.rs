@@ -0,0 +1,89 @@
+//! These tests cover how the `allow-unknown-fields` and `deny-unknown-fields`
+//! features change deserialization. Each test is only compiled under the
+//! combination of features that it describes, so this file should be run once
+//! for every combination:
+//!
+//! ```sh
+//! $ cargo test --test features
+//! $ cargo test --test features --features allow-unknown-fields
+//! $ cargo test --test features --features deny-unknown-fields
+//! ```
+//!
+//! Running `cargo build --all-targets` with the same features also checks that
+//! the examples and the other tests compile. No requests are made.
+
+use curseforge::official::prelude::*;
+
+/// A category with a field, `displayIndex`, that is not known to this crate.
+static UNKNOWN_FIELD: &str = include_str!("fixtures/category_with_unknown_field.json");
+
+/// Pagination with a field, `hasMore`, that is not known to this crate.
+static UNKNOWN_PAGINATION_FIELD: &str =
+    r#"{ "index": 0, "pageSize": 50, "resultCount": 50, "totalCount": 100, "hasMore": true }"#;
+
+/// A mod loader that is not known to this crate.
+static UNKNOWN_VARIANT: &str = "200";
+
+/// Without either feature, unknown fields are ignored.
+#[cfg(not(any(feature = "allow-unknown-fields", feature = "deny-unknown-fields")))]
+#[test]
+fn default_ignores_unknown_fields() {
+    let category: Category = serde_json::from_str(UNKNOWN_FIELD).unwrap();
+
+    assert_eq!(category.id, 4471);
+    assert_eq!(category.is_class, Some(true));
+    assert!(serde_json::from_str::<Pagination>(UNKNOWN_PAGINATION_FIELD).is_ok());
+}
+
+/// Without `allow-unknown-fields`, unknown variants are always an error.
+#[cfg(not(feature = "allow-unknown-fields"))]
+#[test]
+fn unknown_variant_is_error() {
+    assert!(serde_json::from_str::<ModLoaderType>(UNKNOWN_VARIANT).is_err());
+}
+
+/// With `allow-unknown-fields`, unknown fields are kept in `other_fields`.
+#[cfg(feature = "allow-unknown-fields")]
+#[test]
+fn allow_captures_unknown_fields() {
+    let category: Category = serde_json::from_str(UNKNOWN_FIELD).unwrap();
+
+    assert_eq!(category.id, 4471);
+    assert_eq!(category.other_fields["displayIndex"], 3);
+    assert!(category.other_fields.get("name").is_none());
+
+    let pagination: Pagination = serde_json::from_str(UNKNOWN_PAGINATION_FIELD).unwrap();
+
+    assert_eq!(pagination.total_count, 100);
+    assert_eq!(pagination.other_fields["hasMore"], true);
+}
+
+/// With `allow-unknown-fields`, unknown variants become `Unknown`.
+#[cfg(feature = "allow-unknown-fields")]
+#[test]
+fn allow_captures_unknown_variants() {
+    let loader: ModLoaderType = serde_json::from_str(UNKNOWN_VARIANT).unwrap();
+
+    assert_eq!(loader, ModLoaderType::Unknown);
+}
+
+/// With `deny-unknown-fields`, unknown fields are an error that names them.
+#[cfg(feature = "deny-unknown-fields")]
+#[test]
+fn deny_rejects_unknown_fields() {
+    let error = serde_json::from_str::<Category>(UNKNOWN_FIELD).unwrap_err();
+
+    assert!(error.to_string().contains("displayIndex"));
+
+    let error = serde_json::from_str::<Pagination>(UNKNOWN_PAGINATION_FIELD).unwrap_err();
+
+    assert!(error.to_string().contains("hasMore"));
+}
+
+/// Every feature accepts a response without unknown fields.
+#[test]
+fn known_fields_always_accepted() {
+    let fixture = include_str!("fixtures/category_without_class.json");
+
+    assert!(serde_json::from_str::<Category>(fixture).is_ok());
+}
@@ -0,0 +1,356 @@
+//! These tests cover the helpers for [`ProjectFile`] and its download URL,
+//! using a fixture so that no requests are made.
+
+use curseforge::official::prelude::*;
+
+fn file_named(file_name: &str) -> ProjectFile {
+    let fixture = include_str!("fixtures/file_relative_download_url.json");
+    let mut file: ProjectFile = serde_json::from_str(fixture).unwrap();
+
+    file.file_name = file_name.to_owned();
+    file
+}
+
+/// Separators, characters that are not allowed on Windows, and reserved
+/// names are replaced, and an empty name falls back to the ID of the file.
+#[test]
+fn safe_filename() {
+    let cases = [
+        (
+            "jei-1.20.1-forge-15.2.0.27.jar",
+            "jei-1.20.1-forge-15.2.0.27.jar",
+        ),
+        ("../../mods/evil.jar", ".._.._mods_evil.jar"),
+        ("C:\\mods\\evil.jar", "C__mods_evil.jar"),
+        ("what?<is>this*.zip", "what__is_this_.zip"),
+        ("trailing. ", "trailing"),
+        ("con.jar", "_con.jar"),
+        ("LPT1", "_LPT1"),
+        ("console.jar", "console.jar"),
+        ("..", "_"),
+    ];
+
+    for (file_name, expected) in cases {
+        let file = file_named(file_name);
+
+        assert_eq!(file.safe_filename(), expected);
+        assert_eq!(file.file_name, file_name);
+    }
+
+    let file = file_named("");
+    assert_eq!(file.safe_filename(), file.id.to_string());
+}
+
+/// The ID of a file is split into the thousands and the remainder.
+#[test]
+fn cdn_path_components() {
+    assert_eq!(e::cdn_path_components(0), (0, 0));
+    assert_eq!(e::cdn_path_components(999), (0, 999));
+    assert_eq!(e::cdn_path_components(1000), (1, 0));
+    assert_eq!(e::cdn_path_components(1001), (1, 1));
+    assert_eq!(e::cdn_path_components(4593548), (4593, 548));
+}
+
+/// The constructed URL matches the one the API sent, and the file name is
+/// percent-encoded as a single path segment.
+#[test]
+fn construct_download_url() {
+    let file = file_named("jei-1.20.1-forge-15.2.0.27.jar");
+
+    assert_eq!(
+        e::construct_download_url(file.id, &file.file_name),
+        file.resolved_download_url().unwrap().unwrap()
+    );
+    assert_eq!(
+        e::construct_download_url(3000012, "Some Mod #1?.jar").as_str(),
+        "https://edge.forgecdn.net/files/3000/12/Some%20Mod%20%231%3F.jar"
+    );
+}
+
+/// Each hash is keyed by its algorithm, and is the same one returned by its
+/// accessor.
+#[test]
+fn hash_map() {
+    let file = file_named("jei-1.20.1-forge-15.2.0.27.jar");
+    let hashes = file.hash_map();
+
+    assert_eq!(hashes.len(), 2);
+    assert_eq!(hashes[&HashAlgorithm::Sha1], file.sha1().unwrap());
+    assert_eq!(hashes[&HashAlgorithm::Md5], file.md5().unwrap());
+    assert_eq!(
+        file.sha1(),
+        Some("7c1b2bd3b9d5b0e7a3f1c7b0a7b7d9e44b1b9c3e")
+    );
+}
+
+/// The newest of the latest files is the one with the latest date, and the
+/// higher ID when the dates are tied.
+#[test]
+fn newest_file() {
+    let project = include_str!("fixtures/project.json");
+    let mut project: Project = serde_json::from_str(project).unwrap();
+
+    assert!(project.newest_file().is_none());
+
+    let older = file_named("older.jar");
+    let mut newer = file_named("newer.jar");
+    let mut tied = file_named("tied.jar");
+
+    newer.file_date = older.file_date + chrono::Duration::days(1);
+    tied.file_date = newer.file_date;
+    tied.id = newer.id - 1;
+
+    project.latest_files = vec![older, newer, tied];
+
+    assert_eq!(project.newest_file().unwrap().file_name, "newer.jar");
+
+    project.latest_files[2].id = project.latest_files[1].id + 1;
+
+    assert_eq!(project.newest_file().unwrap().file_name, "tied.jar");
+}
+
+/// Minecraft files list their mod loaders among their game versions.
+#[test]
+fn minecraft_game_version_tags() {
+    let file = file_named("jei-1.20.1-forge-15.2.0.27.jar");
+
+    assert_eq!(
+        file.game_version_tags(),
+        [
+            GameVersionTag::Loader(ModLoaderType::Forge),
+            GameVersionTag::Other("1.20.1".to_owned()),
+        ]
+    );
+}
+
+/// World of Warcraft files list their release channels as game versions, and
+/// none of them are mod loaders, even one that happens to share a name.
+#[test]
+fn wow_game_version_tags() {
+    let mut file = file_named("DBM-Core-10.2.7.zip");
+
+    file.game_id = 1;
+    file.game_versions = vec![
+        "Retail".to_owned(),
+        "Classic".to_owned(),
+        "Forge".to_owned(),
+    ];
+    file.sortable_game_versions.clear();
+
+    assert_eq!(
+        file.game_version_tags(),
+        [
+            GameVersionTag::Other("Retail".to_owned()),
+            GameVersionTag::Other("Classic".to_owned()),
+            GameVersionTag::Other("Forge".to_owned()),
+        ]
+    );
+
+    let project = include_str!("fixtures/project.json");
+    let mut project: Project = serde_json::from_str(project).unwrap();
+
+    project.game_id = 1;
+    project.latest_files = vec![file];
+
+    assert!(project.supported_loaders().is_empty());
+}
+
+/// A client file that refers to its server pack, a server pack that only
+/// refers back to its parent, and an alternate that only one side refers to.
+#[test]
+fn file_relationship_graph() {
+    let file = |id: i32, name: &str| {
+        let mut file = file_named(name);
+
+        file.id = id;
+        file.parent_project_file_id = None;
+        file.alternate_file_id = Some(0);
+        file.is_server_pack = false;
+        file.server_pack_file_id = None;
+        file
+    };
+
+    let mut client = file(1, "pack-1.0.zip");
+    client.server_pack_file_id = Some(2);
+    client.alternate_file_id = Some(3);
+
+    let mut server = file(2, "pack-1.0-server.zip");
+    server.is_server_pack = true;
+    server.parent_project_file_id = Some(1);
+
+    let lite = file(3, "pack-1.0-lite.zip");
+
+    let mut older = file(4, "pack-0.9.zip");
+    older.alternate_file_id = Some(99);
+
+    let mut older_server = file(5, "pack-0.9-server.zip");
+    older_server.is_server_pack = true;
+    older_server.parent_project_file_id = Some(4);
+
+    let graph = FileGraph::new([client, server, lite, older, older_server]);
+    let ids = |files: Vec<&ProjectFile>| files.iter().map(|file| file.id).collect::<Vec<_>>();
+
+    assert_eq!(graph.edges().len(), 5);
+    assert_eq!(graph.server_pack_of(1).unwrap().id, 2);
+    assert_eq!(graph.server_pack_of(4).unwrap().id, 5);
+    assert!(graph.server_pack_of(2).is_none());
+    assert_eq!(graph.parent_of(2).unwrap().id, 1);
+    assert!(graph.parent_of(1).is_none());
+    assert_eq!(ids(graph.alternates_of(1)), [3]);
+    assert_eq!(ids(graph.alternates_of(3)), [1]);
+    assert!(graph.alternates_of(4).is_empty());
+}
+
+/// Files without a known length add nothing to the total.
+#[test]
+fn total_download_size() {
+    let mut files = vec![
+        file_named("a.jar"),
+        file_named("b.jar"),
+        file_named("c.jar"),
+    ];
+
+    files[0].file_length = 1000;
+    files[1].file_length = 0;
+    files[2].file_length = 2500;
+
+    assert_eq!(
+        curseforge::official::types::total_download_size(&files),
+        3500
+    );
+    assert_eq!(curseforge::official::types::total_download_size(&[]), 0);
+
+    files[1].file_length = -1;
+    assert_eq!(
+        curseforge::official::types::total_download_size(&files),
+        3500
+    );
+}
+
+/// Sizes are shown in the largest unit that they reach once rounded, and
+/// sizes past the largest unit stay in it.
+#[test]
+fn human_size() {
+    let cases = [
+        (-1, "0 B"),
+        (0, "0 B"),
+        (1023, "1023 B"),
+        (1024, "1.0 KiB"),
+        (1536, "1.5 KiB"),
+        (1_048_524, "1023.9 KiB"),
+        (1_048_575, "1.0 MiB"),
+        (1_048_576, "1.0 MiB"),
+        (12_897_484, "12.3 MiB"),
+        (3 * 1024 * 1024 * 1024, "3.0 GiB"),
+        (5 * 1024_i64.pow(5), "5120.0 TiB"),
+    ];
+
+    for (file_length, expected) in cases {
+        let mut file = file_named("file.jar");
+        file.file_length = file_length;
+
+        assert_eq!(file.human_size(), expected);
+    }
+}
+
+/// The age of a file is the time since its date, measured from now.
+#[test]
+fn age() {
+    let mut file = file_named("file.jar");
+    file.file_date = chrono::Utc::now() - chrono::Duration::days(3);
+
+    let age = file.age();
+    assert!(age >= chrono::Duration::days(3));
+    assert!(age < chrono::Duration::days(3) + chrono::Duration::minutes(1));
+}
+
+/// The fingerprint matches known values, and ignores whitespace.
+#[test]
+fn fingerprint() {
+    use curseforge::official::types::fingerprint;
+
+    assert_eq!(fingerprint(b""), 1540447798);
+    assert_eq!(fingerprint(b"abcde"), 3469237630);
+    assert_eq!(fingerprint(b"hello world"), fingerprint(b"helloworld"));
+    assert_eq!(fingerprint(b" \t\r\n"), fingerprint(b""));
+}
+
+/// The SHA-1 hash is checked when there is one, otherwise the MD5 hash, and
+/// a file with neither is checked by its length and fingerprint.
+#[test]
+fn verify_hash() {
+    let mut file = file_named("file.jar");
+    file.hashes = serde_json::from_str(
+        r#"[
+            { "value": "2AAE6C35C94FCFB415DBE95F408B9CE91EE846ED", "algo": 1 },
+            { "value": "0f3c3a1f3e4a7f1b5b8d3a1c9e7d5b3a", "algo": 2 }
+        ]"#,
+    )
+    .unwrap();
+
+    // The SHA-1 hash is preferred, and the wrong MD5 hash is not checked.
+    assert!(file.verify_hash(b"hello world").is_ok());
+    assert!(matches!(
+        file.verify_hash(b"hello_world"),
+        Err(HashMismatch::Sha1 { .. })
+    ));
+
+    file.hashes.remove(0);
+    assert!(matches!(
+        file.verify_hash(b"hello world"),
+        Err(HashMismatch::Md5 { actual, .. }) if actual == "5eb63bbbe01eeed093cb22bb8f5acdc3"
+    ));
+
+    // Without any hashes, the length and fingerprint are checked instead.
+    file.hashes.clear();
+    file.file_length = 11;
+    file.file_fingerprint = 2824650221;
+
+    assert!(file.verify_hash(b"hello world").is_ok());
+    assert!(matches!(
+        file.verify_hash(b"hello"),
+        Err(HashMismatch::Length {
+            expected: 11,
+            actual: 5
+        })
+    ));
+    assert!(matches!(
+        file.verify_hash(b"hello_world"),
+        Err(HashMismatch::Fingerprint {
+            expected: 2824650221,
+            ..
+        })
+    ));
+
+    // Unknown lengths and fingerprints are not checked.
+    file.file_length = 0;
+    file.file_fingerprint = 0;
+    assert!(file.verify_hash(b"anything").is_ok());
+}
+
+/// Contents that are read in several chunks, from a position other than the
+/// start, are checked the same as when they are given at once.
+#[test]
+fn verify_reader() {
+    use std::io::Cursor;
+
+    use curseforge::official::types::fingerprint;
+
+    let bytes = (0..200_000).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+    let mut file = file_named("file.jar");
+    file.hashes.clear();
+    file.file_length = bytes.len() as i64;
+    file.file_fingerprint = i64::from(fingerprint(&bytes));
+
+    assert!(file.verify_reader(Cursor::new(&bytes)).is_ok());
+
+    let mut reader = Cursor::new([b"skipped".as_slice(), &bytes].concat());
+    reader.set_position(7);
+    assert!(file.verify_reader(reader).is_ok());
+
+    file.file_fingerprint += 1;
+    assert!(matches!(
+        file.verify_reader(Cursor::new(&bytes)),
+        Err(HashMismatch::Fingerprint { .. })
+    ));
+}
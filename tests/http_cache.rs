@@ -0,0 +1,120 @@
+//! These tests cover the [`DiskResponseCache`], and the revalidation of
+//! responses with it against a local server.
+
+#![cfg(feature = "disk-cache")]
+
+use std::sync::Arc;
+
+use curseforge::official::prelude::*;
+
+mod common;
+
+fn temporary_cache(name: &str, max_size: u64) -> DiskResponseCache {
+    let dir = std::env::temp_dir().join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+
+    DiskResponseCache::new(dir, max_size)
+}
+
+fn response(etag: &str, size: usize) -> CachedResponse {
+    CachedResponse {
+        etag: etag.to_owned(),
+        body: (0..size).map(|i| i as u8).collect(),
+    }
+}
+
+/// A stored response is returned until it is replaced, and none are returned
+/// after the cache is cleared.
+#[test]
+fn disk_cache_round_trip() {
+    let cache = temporary_cache("curseforge-disk-cache-round-trip", u64::MAX);
+    let key = "https://api.curseforge.com/v1/mods/238222";
+
+    assert_eq!(cache.get(key), None);
+
+    cache.put(key, response("\"abc\"", 1024));
+    assert_eq!(cache.get(key), Some(response("\"abc\"", 1024)));
+
+    cache.put(key, response("\"def\"", 16));
+    assert_eq!(cache.get(key), Some(response("\"def\"", 16)));
+
+    cache.clear().unwrap();
+    assert_eq!(cache.get(key), None);
+}
+
+/// Once the files exceed the maximum size, the least recently stored response
+/// is removed first.
+#[test]
+fn disk_cache_evicts_oldest() {
+    // Random bytes do not compress, so each file is larger than the body.
+    let cache = temporary_cache("curseforge-disk-cache-evicts-oldest", 6000);
+    let body = |seed: u64| {
+        let mut state = seed;
+        (0..2000)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                (state >> 56) as u8
+            })
+            .collect::<Vec<_>>()
+    };
+
+    for i in 0..3 {
+        cache.put(
+            &format!("key-{}", i),
+            CachedResponse {
+                etag: i.to_string(),
+                body: body(i),
+            },
+        );
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+
+    assert_eq!(cache.get("key-0"), None);
+    assert!(cache.get("key-1").is_some());
+    assert!(cache.get("key-2").is_some());
+
+    cache.clear().unwrap();
+}
+
+/// A response with an `ETag` is stored, the next request for it is sent with
+/// `If-None-Match`, and a `304 Not Modified` is replaced with the stored body.
+#[test]
+fn etag_revalidation() {
+    let cache = Arc::new(temporary_cache(
+        "curseforge-disk-cache-etag-revalidation",
+        u64::MAX,
+    ));
+    let body = r#"{"data":"<p>Hi</p>"}"#;
+    let (url, heads) = common::serve_heads([
+        format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\netag: \
+             \"abc\"\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        ),
+        "HTTP/1.1 304 Not Modified\r\netag: \"abc\"\r\nconnection: close\r\n\r\n".to_owned(),
+    ]);
+    let client = Client::builder()
+        .base(format!("{}v1/", url))
+        .response_cache(cache.clone())
+        .build()
+        .unwrap();
+
+    smol::block_on(async {
+        assert_eq!(client.project_description(1).await.unwrap(), "<p>Hi</p>");
+        assert_eq!(client.project_description(1).await.unwrap(), "<p>Hi</p>");
+    });
+
+    let first = heads.recv().unwrap();
+    let second = heads.recv().unwrap();
+    let if_none_match = |head: &[String]| {
+        head.iter()
+            .find(|line| line.to_ascii_lowercase().starts_with("if-none-match:"))
+            .map(|line| line["if-none-match:".len()..].trim().to_owned())
+    };
+
+    assert_eq!(if_none_match(&first), None);
+    assert_eq!(if_none_match(&second).as_deref(), Some("\"abc\""));
+
+    cache.clear().unwrap();
+}
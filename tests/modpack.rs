@@ -0,0 +1,41 @@
+//! These tests read the manifest from the archive of a modpack, and do not
+//! make any requests.
+
+#![cfg(feature = "modpack")]
+
+use curseforge::official::prelude::*;
+use curseforge::Error;
+
+fn uri() -> url::Url {
+    url::Url::parse("https://edge.forgecdn.net/files/1/1/pack.zip").unwrap()
+}
+
+/// The manifest is found at the root of the archive, next to the overrides.
+#[test]
+fn manifest_from_zip() {
+    let archive = std::io::Cursor::new(include_bytes!("fixtures/modpack.zip"));
+    let manifest = ModpackManifest::from_zip(uri(), archive).unwrap();
+
+    assert_eq!(manifest.name, "Example Pack");
+    assert_eq!(manifest.overrides.as_deref(), Some("overrides"));
+    assert_eq!(
+        manifest
+            .files
+            .iter()
+            .map(|file| (file.project_id, file.file_id))
+            .collect::<Vec<_>>(),
+        [(238222, 4593548), (32274, 4646736)]
+    );
+}
+
+/// A file that is not an archive is reported as such, rather than as a
+/// missing manifest.
+#[test]
+fn manifest_from_invalid_zip() {
+    let archive = std::io::Cursor::new(b"not a zip archive".to_vec());
+
+    assert!(matches!(
+        ModpackManifest::from_zip(uri(), archive),
+        Err(Error::Zip(_))
+    ));
+}
@@ -0,0 +1,25 @@
+//! These tests cover the helpers on [`Pagination`], and do not make any
+//! requests.
+
+use curseforge::official::prelude::*;
+
+/// The progress is the share of the results up to the end of the page, and an
+/// empty result is complete.
+#[test]
+fn pagination_progress() {
+    let pagination = |index, result_count, total_count| Pagination {
+        index,
+        page_size: 50,
+        result_count,
+        total_count,
+        #[cfg(feature = "allow-unknown-fields")]
+        other_fields: Default::default(),
+    };
+
+    assert_eq!(pagination(0, 0, 0).progress(), 1.0);
+    assert_eq!(pagination(0, 50, 200).progress(), 0.25);
+    assert_eq!(pagination(150, 50, 200).progress(), 1.0);
+    assert_eq!(pagination(4950, 50, 1_000_000).progress(), 0.5);
+    assert_eq!(pagination(9950, 50, 1_000_000).progress(), 1.0);
+    assert!(pagination(50, 50, 200).progress() > pagination(0, 50, 200).progress());
+}
@@ -0,0 +1,272 @@
+//! These tests serialize every parameter structure with all fields populated,
+//! and check that the keys sent to the API match the documented names exactly.
+//! A misspelled key is silently ignored by the API, which disables the filter.
+
+use std::collections::BTreeSet;
+
+use curseforge::official::prelude::*;
+use curseforge::Error;
+use serde::Serialize;
+
+const GAME_MINECRAFT: i32 = 432;
+
+fn query_keys<T: Serialize>(params: &T) -> BTreeSet<String> {
+    serde_qs::to_string(params)
+        .unwrap()
+        .split('&')
+        .map(|pair| pair.split('=').next().unwrap().to_owned())
+        .collect()
+}
+
+fn body_keys<T: Serialize>(body: &T) -> BTreeSet<String> {
+    match serde_json::to_value(body).unwrap() {
+        serde_json::Value::Object(map) => map.into_iter().map(|(key, _)| key).collect(),
+        other => panic!("expected an object, found {}", other),
+    }
+}
+
+fn keys(expected: &[&str]) -> BTreeSet<String> {
+    expected.iter().map(|key| (*key).to_owned()).collect()
+}
+
+/// <https://docs.curseforge.com/#get-games>
+#[test]
+fn games_params() {
+    let params = GamesParams {
+        index: Some(0),
+        page_size: Some(50),
+    };
+
+    assert_eq!(query_keys(&params), keys(&["index", "pageSize"]));
+}
+
+/// <https://docs.curseforge.com/#get-categories>
+#[test]
+fn categories_params() {
+    let params = CategoriesParams {
+        game_id: GAME_MINECRAFT,
+        class_id: Some(6),
+        classes_only: Some(true),
+        parent_category_id: Some(6),
+    };
+
+    assert_eq!(
+        query_keys(&params),
+        keys(&["gameId", "classId", "classesOnly"])
+    );
+}
+
+/// Strings are sent without quotes, and numbers and booleans as they are.
+#[test]
+fn query_values() {
+    let mut params = ProjectSearchParams::game(GAME_MINECRAFT);
+    params.search_filter = Some("jei".to_owned());
+
+    assert_eq!(params.query_string(), "gameId=432&searchFilter=jei");
+
+    let mut params = CategoriesParams::game(GAME_MINECRAFT);
+    params.classes_only = Some(true);
+
+    assert_eq!(
+        serde_qs::to_string(&params).unwrap(),
+        "gameId=432&classesOnly=true"
+    );
+}
+
+/// <https://docs.curseforge.com/#search-mods>
+#[test]
+fn project_search_params() {
+    let params = ProjectSearchParams {
+        game_id: GAME_MINECRAFT,
+        class_id: Some(6),
+        category_id: Some(423),
+        game_version: Some("1.18.2".to_owned()),
+        search_filter: Some("jei".to_owned()),
+        sort_field: Some(SearchSort::Popularity),
+        sort_order: Some(SearchSortOrder::Descending),
+        mod_loader: Some(ModLoaderType::Forge),
+        game_version_type_id: Some(73250),
+        author_id: Some(17072262),
+        slug: Some("jei".to_owned()),
+        index: Some(0),
+        page_size: Some(50),
+        extra: Default::default(),
+    };
+
+    assert_eq!(
+        query_keys(&params),
+        keys(&[
+            "gameId",
+            "classId",
+            "categoryId",
+            "gameVersion",
+            "searchFilter",
+            "sortField",
+            "sortOrder",
+            "modLoaderType",
+            "gameVersionTypeId",
+            "authorId",
+            "slug",
+            "index",
+            "pageSize",
+        ])
+    );
+}
+
+/// <https://docs.curseforge.com/#get-mod-files>
+#[test]
+fn project_files_params() {
+    let params = ProjectFilesParams {
+        game_version: Some("1.18.2".to_owned()),
+        mod_loader: Some(ModLoaderType::Forge),
+        game_version_type_id: Some(73250),
+        release_type: Some(FileReleaseType::Release),
+        index: Some(0),
+        page_size: Some(50),
+    };
+
+    assert_eq!(
+        query_keys(&params),
+        keys(&[
+            "gameVersion",
+            "modLoaderType",
+            "gameVersionTypeId",
+            "index",
+            "pageSize",
+        ])
+    );
+}
+
+/// <https://docs.curseforge.com/#tocS_GetFeaturedModsRequestBody>
+#[test]
+fn featured_projects_body() {
+    let body = FeaturedProjectsBody {
+        game_id: GAME_MINECRAFT,
+        excluded_mod_ids: vec![238222],
+        game_version_type_id: Some(73250),
+    };
+
+    assert_eq!(
+        body_keys(&body),
+        keys(&["gameId", "excludedModIds", "gameVersionTypeId"])
+    );
+}
+
+/// The API cannot search across games, so this is rejected before a request.
+#[test]
+fn project_search_requires_game() {
+    assert!(matches!(
+        ProjectSearchParams::for_any_supported_game(),
+        Err(Error::InvalidParams(_))
+    ));
+    assert!(matches!(
+        ProjectSearchParams::game(0).validate(),
+        Err(Error::InvalidParams(_))
+    ));
+    assert!(ProjectSearchParams::game(GAME_MINECRAFT).validate().is_ok());
+}
+
+/// Extra parameters are appended verbatim, unless they name a known field.
+#[test]
+fn project_search_extra() {
+    let mut params = ProjectSearchParams::game(GAME_MINECRAFT)
+        .extra("primaryAuthorId", "17072262")
+        .extra("gameId", "1")
+        .extra("categoryIds", "[6,423]");
+    params.class_id = Some(6);
+
+    assert_eq!(
+        params.query_string(),
+        "gameId=432&classId=6&categoryIds=%5B6%2C423%5D&primaryAuthorId=17072262"
+    );
+}
+
+/// A saved search can be described with the same names that are sent to the
+/// API, including the fields that are applied by this crate instead.
+#[test]
+fn params_from_config() {
+    let config = r#"{
+        "gameId": 432,
+        "classId": 6,
+        "searchFilter": "jei",
+        "sortField": 2,
+        "sortOrder": "desc",
+        "modLoaderType": 1,
+        "extra": { "primaryAuthorId": "32358" }
+    }"#;
+    let params: ProjectSearchParams = serde_json::from_str(config).unwrap();
+
+    assert_eq!(
+        params,
+        ProjectSearchParams {
+            class_id: Some(6),
+            search_filter: Some("jei".to_owned()),
+            sort_field: Some(SearchSort::Popularity),
+            sort_order: Some(SearchSortOrder::Descending),
+            mod_loader: Some(ModLoaderType::Forge),
+            ..ProjectSearchParams::game(GAME_MINECRAFT)
+        }
+        .extra("primaryAuthorId", "32358")
+    );
+
+    let params: ProjectFilesParams =
+        serde_json::from_str(r#"{ "gameVersion": "1.20.1", "releaseType": 1 }"#).unwrap();
+
+    assert_eq!(params.game_version.as_deref(), Some("1.20.1"));
+    assert_eq!(params.release_type, Some(FileReleaseType::Release));
+
+    let params: CategoriesParams =
+        serde_json::from_str(r#"{ "gameId": 432, "parentCategoryId": 6 }"#).unwrap();
+
+    assert_eq!(params.parent_category_id, Some(6));
+    assert_eq!(query_keys(&params), keys(&["gameId"]));
+}
+
+/// Defaults fill in the fields that a call leaves unset, and a value given for
+/// the call takes precedence.
+#[test]
+fn params_with_defaults() {
+    let defaults: DefaultParams = serde_json::from_str(
+        r#"{
+            "search": {
+                "gameVersion": "1.20.1",
+                "pageSize": 50,
+                "extra": { "primaryAuthorId": "32358", "gameVersionTypeId": "1" }
+            },
+            "files": { "gameVersion": "1.20.1", "modLoaderType": 4 }
+        }"#,
+    )
+    .unwrap();
+
+    let params = ProjectSearchParams {
+        page_size: Some(20),
+        ..ProjectSearchParams::game(GAME_MINECRAFT)
+    }
+    .extra("primaryAuthorId", "17072262")
+    .with_defaults(defaults.search.as_ref().unwrap());
+
+    assert_eq!(
+        params.query_string(),
+        "gameId=432&gameVersion=1.20.1&pageSize=20&gameVersionTypeId=1&primaryAuthorId=17072262"
+    );
+
+    let params = ProjectFilesParams {
+        mod_loader: Some(ModLoaderType::Forge),
+        ..Default::default()
+    }
+    .with_defaults(defaults.files.as_ref().unwrap());
+
+    assert_eq!(params.game_version.as_deref(), Some("1.20.1"));
+    assert_eq!(params.mod_loader, Some(ModLoaderType::Forge));
+    assert_eq!(
+        ProjectFilesParams::default()
+            .with_defaults(defaults.files.as_ref().unwrap())
+            .mod_loader,
+        Some(ModLoaderType::Fabric)
+    );
+
+    // Either set of defaults may be left out.
+    let defaults: DefaultParams = serde_json::from_str(r#"{ "files": {} }"#).unwrap();
+    assert_eq!(defaults.search, None);
+    assert_eq!(defaults.files, Some(ProjectFilesParams::default()));
+}
@@ -0,0 +1,161 @@
+//! These tests cover retrying requests, against a local server that answers
+//! each connection with the next of a list of responses.
+
+use std::time::{Duration, Instant};
+
+use curseforge::official::prelude::*;
+
+mod common;
+
+/// The delay asked for by `Retry-After` replaces the much longer backoff.
+#[test]
+fn retry_after() {
+    let (url, _) = common::serve(&[
+        "HTTP/1.1 429 Too Many Requests\r\nretry-after: 1\r\ncontent-length: 0\r\nconnection: \
+         close\r\n\r\n",
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 19\r\nconnection: \
+         close\r\n\r\n{\"data\":\"<p>x</p>\"}",
+    ]);
+    let client = Client::builder()
+        .base(format!("{}v1/", url))
+        .retry(1, Duration::from_secs(60))
+        .build()
+        .unwrap();
+
+    smol::block_on(async {
+        let started = Instant::now();
+        let description = client.project_description(1).await.unwrap();
+
+        assert_eq!(description.as_str(), "<p>x</p>");
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert!(started.elapsed() < Duration::from_secs(30));
+    });
+}
+
+/// A `Retry-After` longer than the maximum delay is not waited for, and the
+/// response is returned instead.
+#[test]
+fn retry_after_too_long() {
+    let (url, _) = common::serve(&["HTTP/1.1 429 Too Many Requests\r\nretry-after: \
+                                    86400\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"]);
+    let client = Client::builder()
+        .base(format!("{}v1/", url))
+        .retry(1, Duration::from_secs(60))
+        .build()
+        .unwrap();
+
+    smol::block_on(async {
+        let started = Instant::now();
+        let error = client.project_description(1).await.unwrap_err();
+
+        assert!(matches!(
+            error,
+            curseforge::Error::StatusNotOk { status, .. } if status == 429
+        ));
+        assert!(started.elapsed() < Duration::from_secs(30));
+    });
+}
+
+/// The backoff doubles with each attempt, and is capped at the maximum delay
+/// however many attempts there have been.
+#[test]
+fn backoff() {
+    let config = RetryConfig {
+        base_delay: Duration::from_secs(1),
+        max_delay: Duration::from_secs(5),
+        ..RetryConfig::default()
+    };
+
+    assert_eq!(config.backoff(0), Duration::from_secs(1));
+    assert_eq!(config.backoff(2), Duration::from_secs(4));
+    assert_eq!(config.backoff(3), Duration::from_secs(5));
+    assert_eq!(config.backoff(100), Duration::from_secs(5));
+}
+
+/// A page that fails with a transient error is requested again from the same
+/// index, and the stream continues once it succeeds.
+#[test]
+fn retrying_stream() {
+    use futures_lite::StreamExt;
+
+    let (url, requests) = common::serve([
+        "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+            .to_owned(),
+        common::json("200 OK", &common::search_page(0, &[1, 2], 2)),
+    ]);
+    let client = Client::new(format!("{}v1/", url), None, None).unwrap();
+
+    smol::block_on(async {
+        let projects = client
+            .search_projects_iter_retrying(
+                ProjectSearchParams::game(432),
+                3,
+                Duration::from_millis(10),
+            )
+            .map(|project| project.unwrap().id)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(projects, [1, 2]);
+    });
+
+    assert!(requests.recv().unwrap().contains("index=0"));
+    assert!(requests.recv().unwrap().contains("index=0"));
+}
+
+const UNAVAILABLE: &str =
+    "HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+const DESCRIPTION: &str = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: \
+                           19\r\nconnection: close\r\n\r\n{\"data\":\"<p>x</p>\"}";
+
+/// Once the budget has been spent, failed requests are returned without
+/// retrying, even though each has retries left of its own.
+#[test]
+fn retry_budget_spent() {
+    let (url, requests) = common::serve([UNAVAILABLE; 3]);
+    let client = Client::builder()
+        .base(format!("{}v1/", url))
+        .retries(RetryConfig {
+            max_retries: 3,
+            base_delay: Duration::from_millis(10),
+            budget: 1,
+            budget_refill_per_second: 0.0,
+            ..RetryConfig::default()
+        })
+        .build()
+        .unwrap();
+
+    smol::block_on(async {
+        assert!(client.project_description(1).await.is_err());
+        assert!(client.project_description(1).await.is_err());
+    });
+
+    // The first request is retried once, and the second not at all.
+    assert_eq!(requests.try_iter().count(), 3);
+}
+
+/// The budget regains retries over time, so a request made after a pause is
+/// retried again.
+#[test]
+fn retry_budget_refill() {
+    let (url, requests) = common::serve([UNAVAILABLE, DESCRIPTION, UNAVAILABLE, DESCRIPTION]);
+    let client = Client::builder()
+        .base(format!("{}v1/", url))
+        .retries(RetryConfig {
+            max_retries: 1,
+            base_delay: Duration::from_millis(10),
+            budget: 1,
+            budget_refill_per_second: 10.0,
+            ..RetryConfig::default()
+        })
+        .build()
+        .unwrap();
+
+    smol::block_on(async {
+        assert!(client.project_description(1).await.is_ok());
+        smol::Timer::after(Duration::from_millis(200)).await;
+        assert!(client.project_description(1).await.is_ok());
+    });
+
+    assert_eq!(requests.try_iter().count(), 4);
+}
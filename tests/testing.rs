@@ -0,0 +1,41 @@
+//! These tests build values with the helpers of the `testing` feature, the way
+//! that the tests of a dependent crate would, and do not make any requests.
+
+#![cfg(feature = "testing")]
+
+use curseforge::official::prelude::*;
+use curseforge::official::testing;
+
+/// A project and a file can be built from fixtures of their JSON.
+#[test]
+fn project_and_file() {
+    let project = testing::project(include_str!("fixtures/project.json")).unwrap();
+    let file =
+        testing::project_file(include_str!("fixtures/file_relative_download_url.json")).unwrap();
+
+    assert_eq!(project.id, 238222);
+    assert_eq!(file.project_id, project.id);
+}
+
+/// A response keeps the bytes that it was built from, and an invalid value
+/// reports the path to the field that caused it.
+#[test]
+fn responses() {
+    let json = r#"{
+        "data": [],
+        "pagination": { "index": 0, "pageSize": 50, "resultCount": 0, "totalCount": 0 }
+    }"#;
+    let response = testing::paginated_response::<Project>(json).unwrap();
+
+    assert_eq!(response.get_bytes(), json.as_bytes());
+    assert_eq!(response.get_elapsed(), std::time::Duration::ZERO);
+    assert!(response.get_headers().is_empty());
+    assert_eq!(response.rate_limit_remaining(), None);
+    assert!(response.data.is_empty());
+
+    let error = testing::data_response::<ProjectFile>(r#"{ "data": { "id": "1" } }"#).unwrap_err();
+    assert_eq!(error.path().to_string(), "data.id");
+
+    let response = ApiResponse::from_parts(Vec::new(), 1);
+    assert_eq!(response.into_value(), 1);
+}